@@ -58,7 +58,7 @@ export function registerProjectHandlers() {
   ipcMain.handle("project:list-files", async (event, projectPath: string) => {
     console.log(`IPC: Listing files for ${projectPath}`);
     try {
-      const files = listProjectFiles(projectPath, 3); // max depth 3
+      const files = listProjectFiles(projectPath, 3).nodes; // max depth 3
       return files;
     } catch (error) {
       console.error("Error listing files:", error);
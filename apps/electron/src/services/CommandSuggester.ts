@@ -23,7 +23,7 @@ export class CommandSuggester {
     const allPackageJsons: Array<{ dir: string; packageJson: any }> = [];
 
     try {
-      const files = listProjectFiles(projectPath, 3);
+      const files = listProjectFiles(projectPath, 3).nodes;
       console.log(
         `[Step 2] Found ${files.length} top-level items, searching for package.json files...`,
       );
@@ -0,0 +1,391 @@
+use crate::file_analyzer::{
+    find_all_manifests, max_file_size, oversized_file_warning, read_to_string_capped, DEFAULT_MAX_FILE_SIZE_BYTES,
+};
+use crate::file_system::to_forward_slashes;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dependency {
+    pub name: String,
+    /// Base version normalized across ecosystems (e.g. "^18.2.0" and ">=18.2.0,<19" become "18.2.0"),
+    /// or None when the spec doesn't name a version (git, path, "latest", "*")
+    pub version: Option<String>,
+    /// Version requirement exactly as written in the manifest
+    pub version_spec: String,
+    /// "npm", "cargo", "pypi", "go" or "composer"
+    pub ecosystem: String,
+    pub manifest_path: String,
+    /// Declared as a development-only dependency (devDependencies, dev-dependencies, require-dev)
+    pub dev: bool,
+}
+
+/// Reduce an ecosystem-specific version requirement to the base version it names
+///
+/// The first comparator is kept and its operator stripped, so npm (`^1.2.3`, `~1.2`,
+/// `1.x`), Cargo (`1.2`, `=1.2.3`) and PEP 440 (`>=1.2,<2`, `~=1.4`, `==1.2.*`)
+/// requirements all normalize to a plain dotted version. Upper bounds (`<2`, `<=1.4`)
+/// and exclusions (`!=1.0`) name a version that is *not* installed, so a spec whose
+/// first comparator is one of those yields None.
+pub(crate) fn normalize_version(spec: &str) -> Option<String> {
+    let first_clause = spec.split("||").next()?.split(',').next()?.trim();
+    if first_clause.starts_with(['<', '!']) {
+        return None;
+    }
+
+    let first_clause = first_clause.trim_start_matches(['^', '~', '=', '>', 'v', ' ']);
+
+    let mut version = first_clause.split_whitespace().next()?.to_string();
+
+    // Wildcard segments ("1.x", "1.2.*") don't pin anything
+    while version.ends_with(".x") || version.ends_with(".X") || version.ends_with(".*") {
+        version.truncate(version.len() - 2);
+    }
+
+    if version.starts_with(|c: char| c.is_ascii_digit()) {
+        Some(version)
+    } else {
+        None
+    }
+}
+
+fn dependency(name: &str, version_spec: &str, ecosystem: &str, manifest_path: &Path, dev: bool) -> Dependency {
+    Dependency {
+        name: name.to_string(),
+        version: normalize_version(version_spec),
+        version_spec: version_spec.to_string(),
+        ecosystem: ecosystem.to_string(),
+        manifest_path: to_forward_slashes(manifest_path),
+        dev,
+    }
+}
+
+/// Parse `dependencies` and `devDependencies` from a package.json
+pub(crate) fn parse_package_json(content: &str, manifest_path: &Path) -> Vec<Dependency> {
+    let package_json = match serde_json::from_str::<serde_json::Value>(content) {
+        Ok(value) => value,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut dependencies = Vec::new();
+    for section in ["dependencies", "devDependencies"] {
+        if let Some(deps) = package_json.get(section).and_then(|d| d.as_object()) {
+            for (name, spec) in deps {
+                dependencies.push(dependency(name, spec.as_str().unwrap_or(""), "npm", manifest_path, section == "devDependencies"));
+            }
+        }
+    }
+
+    dependencies
+}
+
+/// Parse `[dependencies]`, `[dev-dependencies]` and `[build-dependencies]` from a Cargo.toml
+pub(crate) fn parse_cargo_toml(content: &str, manifest_path: &Path) -> Vec<Dependency> {
+    let cargo_toml = match content.parse::<toml::Table>() {
+        Ok(table) => table,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut dependencies = Vec::new();
+    for section in ["dependencies", "dev-dependencies", "build-dependencies"] {
+        if let Some(deps) = cargo_toml.get(section).and_then(|d| d.as_table()) {
+            for (name, spec) in deps {
+                // `name = "1.0"` or `name = { version = "1.0", features = [...] }`
+                let version_spec = match spec {
+                    toml::Value::String(version) => version.as_str(),
+                    toml::Value::Table(table) => table.get("version").and_then(|v| v.as_str()).unwrap_or(""),
+                    _ => "",
+                };
+                dependencies.push(dependency(name, version_spec, "cargo", manifest_path, section == "dev-dependencies"));
+            }
+        }
+    }
+
+    dependencies
+}
+
+/// Split a PEP 508 requirement (`requests[security]>=2.28; python_version > "3.8"`) into name and spec
+fn split_python_requirement(requirement: &str) -> Option<(&str, &str)> {
+    let requirement = requirement.split(';').next()?.trim();
+    let name_end = requirement
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(requirement.len());
+
+    let name = &requirement[..name_end];
+    if name.is_empty() {
+        return None;
+    }
+
+    // Drop extras such as `[security]` before the version spec
+    let rest = requirement[name_end..].trim_start();
+    let spec = match rest.strip_prefix('[') {
+        Some(extras) => extras.split_once(']').map(|(_, spec)| spec).unwrap_or(""),
+        None => rest,
+    };
+
+    Some((name, spec.trim()))
+}
+
+/// Parse a requirements.txt, skipping comments, options and editable installs
+pub(crate) fn parse_requirements_txt(content: &str, manifest_path: &Path) -> Vec<Dependency> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or("").trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('-'))
+        .filter_map(split_python_requirement)
+        .map(|(name, spec)| dependency(name, spec, "pypi", manifest_path, false))
+        .collect()
+}
+
+/// Parse PEP 621 `[project].dependencies` and Poetry dependency tables from a pyproject.toml
+pub(crate) fn parse_pyproject_toml(content: &str, manifest_path: &Path) -> Vec<Dependency> {
+    let pyproject = match content.parse::<toml::Table>() {
+        Ok(table) => table,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut dependencies = Vec::new();
+
+    if let Some(requirements) = pyproject
+        .get("project")
+        .and_then(|p| p.get("dependencies"))
+        .and_then(|d| d.as_array())
+    {
+        for requirement in requirements.iter().filter_map(|r| r.as_str()) {
+            if let Some((name, spec)) = split_python_requirement(requirement) {
+                dependencies.push(dependency(name, spec, "pypi", manifest_path, false));
+            }
+        }
+    }
+
+    if let Some(poetry) = pyproject.get("tool").and_then(|t| t.get("poetry")) {
+        for section in ["dependencies", "dev-dependencies"] {
+            if let Some(deps) = poetry.get(section).and_then(|d| d.as_table()) {
+                for (name, spec) in deps {
+                    // Poetry lists the interpreter constraint alongside packages
+                    if name == "python" {
+                        continue;
+                    }
+                    let version_spec = match spec {
+                        toml::Value::String(version) => version.as_str(),
+                        toml::Value::Table(table) => table.get("version").and_then(|v| v.as_str()).unwrap_or(""),
+                        _ => "",
+                    };
+                    dependencies.push(dependency(name, version_spec, "pypi", manifest_path, section == "dev-dependencies"));
+                }
+            }
+        }
+    }
+
+    dependencies
+}
+
+/// Read the module path declared by a go.mod (`module example.com/app`)
+pub(crate) fn go_module_path(content: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let line = line.split("//").next().unwrap_or("").trim();
+        line.strip_prefix("module ")
+            .map(|path| path.trim().trim_matches('"').to_string())
+    })
+}
+
+/// Parse `require` directives from a go.mod, both single-line and block forms
+pub(crate) fn parse_go_mod(content: &str, manifest_path: &Path) -> Vec<Dependency> {
+    let mut dependencies = Vec::new();
+    let mut in_require_block = false;
+
+    for line in content.lines() {
+        // `// indirect` markers and other comments carry no requirement
+        let line = line.split("//").next().unwrap_or("").trim();
+
+        let requirement = if in_require_block {
+            if line == ")" {
+                in_require_block = false;
+                continue;
+            }
+            line
+        } else if line == "require (" || line == "require(" {
+            in_require_block = true;
+            continue;
+        } else if let Some(requirement) = line.strip_prefix("require ") {
+            requirement
+        } else {
+            continue;
+        };
+
+        let mut fields = requirement.split_whitespace();
+        if let (Some(name), Some(version)) = (fields.next(), fields.next()) {
+            dependencies.push(dependency(name, version, "go", manifest_path, false));
+        }
+    }
+
+    dependencies
+}
+
+/// Parse `require` and `require-dev` from a composer.json, skipping platform requirements
+pub(crate) fn parse_composer_json(content: &str, manifest_path: &Path) -> Vec<Dependency> {
+    let composer_json = match serde_json::from_str::<serde_json::Value>(content) {
+        Ok(value) => value,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut dependencies = Vec::new();
+    for section in ["require", "require-dev"] {
+        if let Some(deps) = composer_json.get(section).and_then(|d| d.as_object()) {
+            for (name, spec) in deps {
+                // `php` and `ext-*` constrain the runtime, not packages
+                if name == "php" || name.starts_with("ext-") {
+                    continue;
+                }
+                dependencies.push(dependency(name, spec.as_str().unwrap_or(""), "composer", manifest_path, section == "require-dev"));
+            }
+        }
+    }
+
+    dependencies
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyReport {
+    pub dependencies: Vec<Dependency>,
+    /// Manifests skipped for exceeding the size limit
+    pub warnings: Vec<String>,
+}
+
+/// List every dependency declared in the project's manifests in one normalized shape
+///
+/// # Arguments
+/// * `project_path` - Root path of the project
+/// * `max_file_size_bytes` - Manifests larger than this are not read (default: 10 MiB)
+///
+/// # Returns
+/// * `Result<DependencyReport>` - Dependencies from all package.json, Cargo.toml, requirements.txt,
+///   pyproject.toml, go.mod and composer.json files in the tree, ready to feed to an external
+///   audit service, plus a warning for each manifest skipped for its size
+#[napi]
+pub fn analyze_dependencies(project_path: String, max_file_size_bytes: Option<i64>) -> Result<DependencyReport> {
+    let max_file_size = max_file_size(max_file_size_bytes, DEFAULT_MAX_FILE_SIZE_BYTES);
+    let mut dependencies = Vec::new();
+    let mut warnings = Vec::new();
+
+    for manifest in find_all_manifests(project_path)? {
+        let manifest_path = Path::new(&manifest.path);
+        let content = match read_to_string_capped(manifest_path, max_file_size) {
+            Ok(Some(content)) => content,
+            Ok(None) => {
+                warnings.push(oversized_file_warning(manifest_path, max_file_size));
+                continue;
+            }
+            Err(_) => continue,
+        };
+
+        let file_name = manifest_path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        dependencies.extend(match file_name {
+            "package.json" => parse_package_json(&content, manifest_path),
+            "Cargo.toml" => parse_cargo_toml(&content, manifest_path),
+            "requirements.txt" => parse_requirements_txt(&content, manifest_path),
+            "pyproject.toml" => parse_pyproject_toml(&content, manifest_path),
+            "go.mod" => parse_go_mod(&content, manifest_path),
+            "composer.json" => parse_composer_json(&content, manifest_path),
+            _ => Vec::new(),
+        });
+    }
+
+    Ok(DependencyReport { dependencies, warnings })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_version() {
+        assert_eq!(normalize_version("^18.2.0").as_deref(), Some("18.2.0"));
+        assert_eq!(normalize_version(">=2.28,<3").as_deref(), Some("2.28"));
+        assert_eq!(normalize_version("~=1.4").as_deref(), Some("1.4"));
+        assert_eq!(normalize_version("==1.2.*").as_deref(), Some("1.2"));
+        assert_eq!(normalize_version("1.x").as_deref(), Some("1"));
+        assert_eq!(normalize_version("workspace:*"), None);
+        assert_eq!(normalize_version("latest"), None);
+        assert_eq!(normalize_version("<2"), None);
+        assert_eq!(normalize_version("<=1.4.0"), None);
+        assert_eq!(normalize_version("!=1.0"), None);
+        assert_eq!(normalize_version("!=1.0,>=0.9"), None);
+        assert_eq!(normalize_version(">= 1.2.0 < 2").as_deref(), Some("1.2.0"));
+    }
+
+    #[test]
+    fn test_parse_requirements_txt() {
+        let manifest = Path::new("requirements.txt");
+        let deps = parse_requirements_txt("# comment\nrequests[security]>=2.28\n-e .\ndjango==4.2.1 ; python_version > '3.8'\n", manifest);
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].name, "requests");
+        assert_eq!(deps[0].version.as_deref(), Some("2.28"));
+        assert_eq!(deps[1].version_spec, "==4.2.1");
+    }
+
+    #[test]
+    fn test_parse_cargo_toml() {
+        let manifest = Path::new("Cargo.toml");
+        let deps = parse_cargo_toml("[dependencies]\nserde = { version = \"1\", features = [\"derive\"] }\nlocal = { path = \"../local\" }\n", manifest);
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].version.as_deref(), Some("1"));
+        assert_eq!(deps[1].version, None);
+        assert_eq!(deps[1].ecosystem, "cargo");
+    }
+
+    #[test]
+    fn test_parse_go_mod() {
+        let manifest = Path::new("go.mod");
+        let content = "module example.com/app // main module\n\ngo 1.21\n\nrequire github.com/spf13/cobra v1.8.0\n\nrequire (\n\tgithub.com/gin-gonic/gin v1.9.1\n\tgolang.org/x/text v0.14.0 // indirect\n)\n\nreplace example.com/old => ../old\n";
+        assert_eq!(go_module_path(content).as_deref(), Some("example.com/app"));
+
+        let deps = parse_go_mod(content, manifest);
+        let names: Vec<&str> = deps.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["github.com/spf13/cobra", "github.com/gin-gonic/gin", "golang.org/x/text"]);
+        assert_eq!(deps[1].version.as_deref(), Some("1.9.1"));
+        assert_eq!(deps[1].version_spec, "v1.9.1");
+    }
+
+    #[test]
+    fn test_parse_composer_json() {
+        let manifest = Path::new("composer.json");
+        let deps = parse_composer_json(
+            r#"{"require":{"php":">=8.1","ext-json":"*","laravel/framework":"^10.0"},"require-dev":{"phpunit/phpunit":"^10.5"}}"#,
+            manifest,
+        );
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].name, "laravel/framework");
+        assert_eq!(deps[0].version.as_deref(), Some("10.0"));
+        assert_eq!(deps[1].ecosystem, "composer");
+        assert!(!deps[0].dev);
+        assert!(deps[1].dev);
+    }
+
+    #[test]
+    fn test_analyze_dependencies_reports_oversized_manifests() {
+        let project_dir = std::env::temp_dir().join("through_test_analyze_dependencies");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(project_dir.join("api")).unwrap();
+        std::fs::write(project_dir.join("package.json"), r#"{"dependencies":{"react":"^18.2.0"}}"#).unwrap();
+        std::fs::write(project_dir.join("api/go.mod"), "module example.com/api\n\nrequire github.com/gin-gonic/gin v1.9.1\n").unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        let report = analyze_dependencies(project_path.clone(), None).unwrap();
+        assert_eq!(report.dependencies.len(), 2);
+        assert!(report.warnings.is_empty());
+
+        let capped = analyze_dependencies(project_path, Some(50)).unwrap();
+        let names: Vec<&str> = capped.dependencies.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["react"]);
+        assert_eq!(capped.warnings.len(), 1);
+        assert!(capped.warnings[0].contains("go.mod"));
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+}
@@ -1,32 +1,123 @@
+use crate::dependency_analyzer::{
+    go_module_path, parse_cargo_toml, parse_composer_json, parse_go_mod, parse_package_json, parse_pyproject_toml,
+    parse_requirements_txt, Dependency,
+};
+use crate::file_system::to_forward_slashes;
+use lazy_static::lazy_static;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
+use ignore::WalkBuilder;
 
 #[napi(object)]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FileAnalysis {
     pub has_package_json: bool,
     pub has_cargo_toml: bool,
     pub has_requirements_txt: bool,
     pub has_gemfile: bool,
+    pub has_go_mod: bool,
+    pub has_composer_json: bool,
+    /// Node package manager inferred from the lockfile: "pnpm", "yarn", "bun" or "npm"
+    pub package_manager: Option<String>,
+    /// Lockfile name the package manager was inferred from (e.g. "pnpm-lock.yaml")
+    pub lockfile: Option<String>,
+    /// Module path declared in go.mod (e.g. "example.com/app")
+    pub go_module: Option<String>,
     pub dependencies: Vec<String>,
+    /// Dependencies with their version specs, from package.json, Cargo.toml, go.mod and composer.json.
+    /// `version_spec` holds the requirement as written (e.g. "^18.2.0"); `version` is the
+    /// normalized base version ("18.2.0") for comparing against the latest release
+    pub dependency_details: Vec<Dependency>,
     pub file_count: u32,
     pub total_size: i64,
+    /// File counts and sizes per extension, largest first
+    pub by_extension: Vec<ExtensionStats>,
+    /// Framework inferred from dependencies, using the names `get_default_port` accepts
+    /// (e.g. "next", "vite", "angular", "sveltekit", "axum")
+    pub detected_framework: Option<String>,
+    /// Test runner inferred from dependencies or project files (e.g. "jest", "vitest", "pytest", "cargo-test")
+    pub test_framework: Option<String>,
+    /// Whether test directories (`test/`, `tests/`, `__tests__/`) or `*.test.*`/`*.spec.*` files exist
+    pub has_tests: bool,
+    /// Non-fatal issues, such as manifests too large to read
+    pub warnings: Vec<String>,
+    /// Directories skipped because they couldn't be read (e.g. macOS-protected folders)
+    pub permission_denied_paths: Vec<String>,
+    /// Runtime versions pinned in asdf's `.tool-versions`
+    pub tool_versions: Vec<ToolVersion>,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolVersion {
+    /// asdf plugin name (e.g. "nodejs", "python", "ruby")
+    pub tool: String,
+    /// Preferred version; asdf falls back to any further versions listed on the line
+    pub version: String,
+}
+
+/// Default limit for reading a file's contents, to avoid OOM on pathological files
+pub(crate) const DEFAULT_MAX_FILE_SIZE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Read a file as text unless it is larger than `max_bytes`
+///
+/// Returns `Ok(None)` for oversized files so callers can skip them and report a warning.
+pub(crate) fn read_to_string_capped(path: &Path, max_bytes: u64) -> std::io::Result<Option<String>> {
+    if fs::metadata(path)?.len() > max_bytes {
+        return Ok(None);
+    }
+
+    fs::read_to_string(path).map(Some)
+}
+
+/// Resolve a `max_file_size_bytes` option, treating negative limits as 0
+pub(crate) fn max_file_size(max_file_size_bytes: Option<i64>, default: u64) -> u64 {
+    max_file_size_bytes.map(|max| max.max(0) as u64).unwrap_or(default)
+}
+
+/// Warning recorded when a file is skipped for exceeding the size limit
+pub(crate) fn oversized_file_warning(path: &Path, max_bytes: u64) -> String {
+    format!(
+        "Skipped reading {}: larger than {} bytes",
+        path.display(),
+        max_bytes
+    )
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AnalyzeOptions {
+    /// Manifest to read dependencies from instead of the package.json in `project_path`,
+    /// e.g. the monorepo root when analyzing a single sub-package. Accepts package.json,
+    /// Cargo.toml, requirements.txt, pyproject.toml, go.mod and composer.json
+    pub root_manifest_path: Option<String>,
+    /// Files larger than this are never read, only counted (default: 10 MiB)
+    pub max_file_size_bytes: Option<i64>,
+    /// Skip files matched by `.gitignore`, `.ignore` and the global git excludes (default: true)
+    pub respect_gitignore: Option<bool>,
 }
 
 /// Analyze project files and extract metadata
 ///
 /// # Arguments
 /// * `project_path` - Root path of the project to analyze
+/// * `options` - Optional analysis settings (see `AnalyzeOptions`)
 ///
 /// # Returns
 /// * `Result<FileAnalysis>` - Analysis results including file counts, dependencies, and detected configuration files
 #[napi]
-pub fn analyze_project_files(project_path: String) -> Result<FileAnalysis> {
+pub fn analyze_project_files(project_path: String, options: Option<AnalyzeOptions>) -> Result<FileAnalysis> {
     let path = Path::new(&project_path);
+    let options = options.unwrap_or_default();
+    let max_file_size = max_file_size(options.max_file_size_bytes, DEFAULT_MAX_FILE_SIZE_BYTES);
 
     if !path.exists() {
         return Err(Error::new(
@@ -48,9 +139,22 @@ pub fn analyze_project_files(project_path: String) -> Result<FileAnalysis> {
         has_cargo_toml: false,
         has_requirements_txt: false,
         has_gemfile: false,
+        has_go_mod: false,
+        has_composer_json: false,
+        package_manager: None,
+        lockfile: None,
+        go_module: None,
         dependencies: Vec::new(),
+        dependency_details: Vec::new(),
         file_count: 0,
         total_size: 0,
+        by_extension: Vec::new(),
+        detected_framework: None,
+        test_framework: None,
+        has_tests: false,
+        warnings: Vec::new(),
+        permission_denied_paths: Vec::new(),
+        tool_versions: Vec::new(),
     };
 
     // Check for key configuration files
@@ -58,92 +162,1396 @@ pub fn analyze_project_files(project_path: String) -> Result<FileAnalysis> {
     let cargo_toml_path = path.join("Cargo.toml");
     let requirements_txt_path = path.join("requirements.txt");
     let gemfile_path = path.join("Gemfile");
+    let go_mod_path = path.join("go.mod");
+    let composer_json_path = path.join("composer.json");
 
     analysis.has_package_json = package_json_path.exists();
     analysis.has_cargo_toml = cargo_toml_path.exists();
     analysis.has_requirements_txt = requirements_txt_path.exists();
     analysis.has_gemfile = gemfile_path.exists();
+    analysis.has_go_mod = go_mod_path.exists();
+    analysis.has_composer_json = composer_json_path.exists();
+
+    if let Some((lockfile, package_manager)) = detect_package_manager(path) {
+        analysis.lockfile = Some(lockfile.to_string());
+        analysis.package_manager = Some(package_manager.to_string());
+    }
+
+    // Dependencies come from the scoped package.json unless a root manifest is given
+    let dependency_manifest_path = match &options.root_manifest_path {
+        Some(manifest_path) => {
+            let manifest_path = PathBuf::from(manifest_path);
+            if !manifest_path.is_file() {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!("Root manifest does not exist: {}", manifest_path.display()),
+                ));
+            }
+            Some(manifest_path)
+        }
+        None if analysis.has_package_json => Some(package_json_path),
+        None => None,
+    };
+
+    // Extract dependencies with the parser matching the manifest's file name
+    if let Some(manifest_path) = dependency_manifest_path {
+        let file_name = manifest_path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let parse: fn(&str, &Path) -> Vec<Dependency> = match file_name {
+            "package.json" => parse_package_json,
+            "Cargo.toml" => parse_cargo_toml,
+            "requirements.txt" => parse_requirements_txt,
+            "pyproject.toml" => parse_pyproject_toml,
+            "go.mod" => parse_go_mod,
+            "composer.json" => parse_composer_json,
+            _ => {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!("Unsupported root manifest: {}", manifest_path.display()),
+                ))
+            }
+        };
+
+        match read_to_string_capped(&manifest_path, max_file_size) {
+            Ok(Some(content)) => push_dependencies(&mut analysis, parse(&content, &manifest_path)),
+            Ok(None) => analysis.warnings.push(oversized_file_warning(&manifest_path, max_file_size)),
+            Err(_) => {}
+        }
+    }
+
+    // Cargo crates, Go modules and Composer packages
+    if analysis.has_cargo_toml {
+        match read_to_string_capped(&cargo_toml_path, max_file_size) {
+            Ok(Some(content)) => push_dependencies(&mut analysis, parse_cargo_toml(&content, &cargo_toml_path)),
+            Ok(None) => analysis.warnings.push(oversized_file_warning(&cargo_toml_path, max_file_size)),
+            Err(_) => {}
+        }
+    }
 
-    // Extract dependencies from package.json if it exists
-    if analysis.has_package_json {
-        if let Ok(package_content) = fs::read_to_string(&package_json_path) {
-            if let Ok(package_json) = serde_json::from_str::<serde_json::Value>(&package_content) {
-                // Extract dependencies
-                if let Some(deps) = package_json.get("dependencies").and_then(|d| d.as_object()) {
-                    for key in deps.keys() {
-                        analysis.dependencies.push(key.clone());
+    if analysis.has_go_mod {
+        match read_to_string_capped(&go_mod_path, max_file_size) {
+            Ok(Some(content)) => {
+                analysis.go_module = go_module_path(&content);
+                push_dependencies(&mut analysis, parse_go_mod(&content, &go_mod_path));
+            }
+            Ok(None) => analysis.warnings.push(oversized_file_warning(&go_mod_path, max_file_size)),
+            Err(_) => {}
+        }
+    }
+
+    if analysis.has_composer_json {
+        match read_to_string_capped(&composer_json_path, max_file_size) {
+            Ok(Some(content)) => {
+                push_dependencies(&mut analysis, parse_composer_json(&content, &composer_json_path));
+            }
+            Ok(None) => analysis.warnings.push(oversized_file_warning(&composer_json_path, max_file_size)),
+            Err(_) => {}
+        }
+    }
+
+    // Pinned runtime versions from asdf
+    let tool_versions_path = path.join(".tool-versions");
+    match read_to_string_capped(&tool_versions_path, max_file_size) {
+        Ok(Some(content)) => analysis.tool_versions = parse_tool_versions(&content),
+        Ok(None) => analysis.warnings.push(oversized_file_warning(&tool_versions_path, max_file_size)),
+        Err(_) => {}
+    }
+
+    analysis.detected_framework = detect_framework(&analysis.dependencies);
+    analysis.test_framework = detect_test_framework(path, &analysis, max_file_size);
+
+    let mut extensions: HashMap<String, ExtensionStats> = HashMap::new();
+
+    // Walk directory tree respecting .gitignore unless opted out
+    for entry in project_walker_with(path, options.respect_gitignore.unwrap_or(true)) {
+        match entry {
+            Ok(entry) => {
+                if !analysis.has_tests && is_test_entry(&entry) {
+                    analysis.has_tests = true;
+                }
+
+                if entry.file_type().is_some_and(|t| t.is_file()) {
+                    analysis.file_count += 1;
+
+                    if let Ok(metadata) = entry.metadata() {
+                        analysis.total_size += metadata.len() as i64;
+                        tally_extension(&mut extensions, entry.path(), metadata.len());
                     }
                 }
+            }
+            Err(e) => {
+                // Skip entries that can't be read, but report permission problems
+                if e.io_error().map(|io| io.kind()) == Some(std::io::ErrorKind::PermissionDenied) {
+                    if let Some(denied_path) = walk_error_path(&e) {
+                        analysis.permission_denied_paths.push(to_forward_slashes(denied_path));
+                    }
+                }
+                continue;
+            }
+        }
+    }
+
+    analysis.by_extension = sorted_extension_stats(extensions);
+
+    Ok(analysis)
+}
+
+/// Node lockfiles and the package manager that writes them, most specific first
+const NODE_LOCKFILES: &[(&str, &str)] = &[
+    ("pnpm-lock.yaml", "pnpm"),
+    ("yarn.lock", "yarn"),
+    ("bun.lockb", "bun"),
+    ("bun.lock", "bun"),
+    ("package-lock.json", "npm"),
+];
+
+/// Find the lockfile at the project root and its package manager
+///
+/// When several lockfiles exist the most recently modified one wins, since it reflects the
+/// last install; ties go to the more specific manager (npm's lockfile is often left behind).
+fn detect_package_manager(path: &Path) -> Option<(&'static str, &'static str)> {
+    NODE_LOCKFILES
+        .iter()
+        .rev()
+        .filter_map(|&(lockfile, manager)| modified_time(&path.join(lockfile)).map(|modified| (lockfile, manager, modified)))
+        // max_by_key keeps the last maximum, i.e. the earliest entry of NODE_LOCKFILES
+        .max_by_key(|(_, _, modified)| *modified)
+        .map(|(lockfile, manager, _)| (lockfile, manager))
+}
+
+/// Add dependencies to the analysis, listing each name only once
+fn push_dependencies(analysis: &mut FileAnalysis, dependencies: Vec<Dependency>) {
+    for dependency in dependencies {
+        if !analysis.dependencies.contains(&dependency.name) {
+            analysis.dependencies.push(dependency.name.clone());
+        }
+        analysis.dependency_details.push(dependency);
+    }
+}
+
+/// Parse asdf's `.tool-versions` format: `<tool> <version> [fallback versions...]` per line,
+/// with `#` starting a comment
+fn parse_tool_versions(content: &str) -> Vec<ToolVersion> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let line = line.split('#').next().unwrap_or("");
+            let mut fields = line.split_whitespace();
+            let tool = fields.next()?;
+            let version = fields.next()?;
+            Some(ToolVersion {
+                tool: tool.to_string(),
+                version: version.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Infer the test runner from JS dependencies, Python test config or a Cargo manifest
+fn detect_test_framework(path: &Path, analysis: &FileAnalysis, max_file_size: u64) -> Option<String> {
+    // Unit test runners take precedence over end-to-end runners
+    for (dependency, framework) in [
+        ("vitest", "vitest"),
+        ("jest", "jest"),
+        ("mocha", "mocha"),
+        ("@playwright/test", "playwright"),
+        ("cypress", "cypress"),
+    ] {
+        if analysis.dependencies.iter().any(|d| d == dependency) {
+            return Some(framework.to_string());
+        }
+    }
+
+    let mentions_pytest = ["requirements.txt", "pyproject.toml"].iter().any(|file| {
+        read_to_string_capped(&path.join(file), max_file_size)
+            .ok()
+            .flatten()
+            .is_some_and(|content| content.contains("pytest"))
+    });
+    if mentions_pytest || path.join("pytest.ini").exists() || path.join("conftest.py").exists() {
+        return Some("pytest".to_string());
+    }
+
+    if analysis.has_cargo_toml {
+        return Some("cargo-test".to_string());
+    }
+
+    None
+}
+
+/// Dependencies that identify a framework, checked in order so meta-frameworks
+/// (Next, Nuxt, SvelteKit, ...) win over the bundler or UI library they build on
+const FRAMEWORK_DEPENDENCIES: &[(&str, &str)] = &[
+    ("next", "next"),
+    ("nuxt", "nuxt"),
+    ("@sveltejs/kit", "sveltekit"),
+    ("@remix-run/react", "remix"),
+    ("astro", "astro"),
+    ("gatsby", "gatsby"),
+    ("@angular/core", "angular"),
+    ("@nestjs/core", "nest"),
+    ("svelte", "sveltekit"),
+    ("vite", "vite"),
+    ("vue", "vue"),
+    ("react-scripts", "cra"),
+    ("fastify", "fastify"),
+    ("express", "express"),
+    ("actix-web", "actix"),
+    ("axum", "axum"),
+    ("rocket", "rocket"),
+];
+
+/// Infer the framework from dependency names (npm packages and Cargo crates)
+fn detect_framework(dependencies: &[String]) -> Option<String> {
+    FRAMEWORK_DEPENDENCIES
+        .iter()
+        .find(|(dependency, _)| dependencies.iter().any(|d| d == dependency))
+        .map(|(_, framework)| framework.to_string())
+}
+
+/// Whether a walked entry is a test directory or a `*.test.*`/`*.spec.*` file
+fn is_test_entry(entry: &ignore::DirEntry) -> bool {
+    let file_name = entry.file_name().to_string_lossy();
+
+    if entry.file_type().is_some_and(|t| t.is_dir()) {
+        return matches!(file_name.as_ref(), "test" | "tests" | "__tests__");
+    }
+
+    file_name.contains(".test.") || file_name.contains(".spec.")
+}
+
+/// Walk a project tree, skipping common build directories and gitignored files
+pub(crate) fn project_walker(path: &Path) -> ignore::Walk {
+    project_walker_with(path, true)
+}
+
+/// Walk a project tree, skipping common build directories and, when `respect_gitignore`
+/// is set, anything matched by `.gitignore`, `.ignore` or the global git excludes.
+/// Hidden files are included, and ignore files apply even outside a git repository.
+pub(crate) fn project_walker_with(path: &Path, respect_gitignore: bool) -> ignore::Walk {
+    project_walk_builder(path, respect_gitignore).build()
+}
+
+/// The builder behind `project_walker_with`, for walks that need extra settings such as a depth limit
+fn project_walk_builder(path: &Path, respect_gitignore: bool) -> WalkBuilder {
+    let mut builder = WalkBuilder::new(path);
+    builder
+        .follow_links(false)
+        .hidden(false)
+        .git_ignore(respect_gitignore)
+        .git_global(respect_gitignore)
+        .git_exclude(respect_gitignore)
+        .ignore(respect_gitignore)
+        .parents(respect_gitignore)
+        .require_git(false)
+        .sort_by_file_name(|a, b| a.cmp(b))
+        .filter_entry(|e| {
+            let path = e.path();
+            let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+
+            // Skip common directories that should be ignored
+            !matches!(
+                file_name,
+                "node_modules" | ".git" | "target" | "dist" | "build" | ".next" | "out" | "__pycache__" | ".venv" | "venv"
+            )
+        });
+    builder
+}
+
+/// Path a walk error refers to, if any
+fn walk_error_path(error: &ignore::Error) -> Option<&Path> {
+    match error {
+        ignore::Error::WithPath { path, .. } => Some(path),
+        ignore::Error::WithDepth { err, .. } | ignore::Error::WithLineNumber { err, .. } => walk_error_path(err),
+        _ => None,
+    }
+}
+
+lazy_static! {
+    /// Work markers left in comments ("// TODO: ...", "# FIXME(jane) ...")
+    static ref TODO_MARKER_REGEX: Regex = Regex::new(r"\b(TODO|FIXME|HACK|XXX)\b").expect("valid TODO marker regex");
+}
+
+/// Default cap on the number of items returned by `find_todo_comments`
+const DEFAULT_TODO_LIMIT: u32 = 1000;
+
+/// Default largest file `find_todo_comments` reads; bigger files are almost never hand-written code
+const TODO_MAX_FILE_SIZE_BYTES: u64 = 1024 * 1024;
+
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TodoItem {
+    /// File path relative to the project root, with forward slashes
+    pub path: String,
+    /// 1-based line number
+    pub line: u32,
+    /// "TODO", "FIXME", "HACK" or "XXX"
+    pub kind: String,
+    /// Comment text following the marker
+    pub text: String,
+}
+
+/// Find the work markers on each line of a file's contents
+fn scan_todo_comments(content: &str, relative_path: &str) -> Vec<TodoItem> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let marker = TODO_MARKER_REGEX.find(line)?;
+            let text = line[marker.end()..]
+                .trim_start_matches(|c: char| c == ':' || c.is_whitespace())
+                .trim_end()
+                .trim_end_matches("*/")
+                .trim_end();
+            Some(TodoItem {
+                path: relative_path.to_string(),
+                line: index as u32 + 1,
+                kind: marker.as_str().to_string(),
+                text: text.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoSearch {
+    pub items: Vec<TodoItem>,
+    /// Files skipped for exceeding the size limit
+    pub warnings: Vec<String>,
+}
+
+/// Find TODO, FIXME, HACK and XXX comments across the project
+///
+/// # Arguments
+/// * `project_path` - Root path of the project
+/// * `max_results` - Maximum number of items to return (default: 1000)
+/// * `max_file_size_bytes` - Files larger than this are not searched (default: 1 MiB)
+///
+/// # Returns
+/// * `Result<TodoSearch>` - Markers in walk order, plus a warning for each file skipped for
+///   its size. Skips ignored directories and binary files
+#[napi]
+pub fn find_todo_comments(
+    project_path: String,
+    max_results: Option<u32>,
+    max_file_size_bytes: Option<i64>,
+) -> Result<TodoSearch> {
+    let path = Path::new(&project_path);
+
+    if !path.is_dir() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Project path is not a directory: {}", project_path),
+        ));
+    }
+
+    let limit = max_results.unwrap_or(DEFAULT_TODO_LIMIT) as usize;
+    let max_file_size = max_file_size(max_file_size_bytes, TODO_MAX_FILE_SIZE_BYTES);
+    let mut items = Vec::new();
+    let mut warnings = Vec::new();
+
+    for entry in project_walker(path).flatten() {
+        if items.len() >= limit {
+            break;
+        }
+
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+
+        // Non-UTF-8 files fail to read and are treated as binary, as are files with NUL bytes
+        let content = match read_to_string_capped(entry.path(), max_file_size) {
+            Ok(Some(content)) if !content.contains('\0') => content,
+            Ok(None) => {
+                warnings.push(oversized_file_warning(entry.path(), max_file_size));
+                continue;
+            }
+            _ => continue,
+        };
+
+        let relative_path = entry
+            .path()
+            .strip_prefix(path)
+            .map(to_forward_slashes)
+            .unwrap_or_default();
+        items.extend(scan_todo_comments(&content, &relative_path));
+    }
+
+    items.truncate(limit);
+    Ok(TodoSearch { items, warnings })
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageStats {
+    pub language: String,
+    pub bytes: i64,
+    /// Share of the breakdown's total bytes, from 0 to 100
+    pub percentage: f64,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageBreakdown {
+    pub primary_language: Option<String>,
+    /// Languages sorted by bytes, largest first
+    pub languages: Vec<LanguageStats>,
+}
+
+/// Map a file extension to a language name and whether it is a programming language
+/// (as opposed to markup, styling or configuration)
+fn language_for_extension(extension: &str) -> Option<(&'static str, bool)> {
+    let language = match extension {
+        "ts" | "tsx" | "mts" | "cts" => ("TypeScript", true),
+        "js" | "jsx" | "mjs" | "cjs" => ("JavaScript", true),
+        "rs" => ("Rust", true),
+        "py" | "pyi" => ("Python", true),
+        "rb" => ("Ruby", true),
+        "go" => ("Go", true),
+        "php" => ("PHP", true),
+        "java" => ("Java", true),
+        "kt" | "kts" => ("Kotlin", true),
+        "swift" => ("Swift", true),
+        "c" | "h" => ("C", true),
+        "cpp" | "cc" | "cxx" | "hpp" | "hh" => ("C++", true),
+        "cs" => ("C#", true),
+        "dart" => ("Dart", true),
+        "ex" | "exs" => ("Elixir", true),
+        "scala" => ("Scala", true),
+        "lua" => ("Lua", true),
+        "sh" | "bash" | "zsh" => ("Shell", true),
+        "vue" => ("Vue", true),
+        "svelte" => ("Svelte", true),
+        "astro" => ("Astro", true),
+        "html" | "htm" => ("HTML", false),
+        "css" => ("CSS", false),
+        "scss" | "sass" => ("SCSS", false),
+        "less" => ("Less", false),
+        "md" | "mdx" => ("Markdown", false),
+        "json" => ("JSON", false),
+        "yaml" | "yml" => ("YAML", false),
+        "toml" => ("TOML", false),
+        "xml" => ("XML", false),
+        _ => return None,
+    };
+
+    Some(language)
+}
+
+/// Compute the dominant language of a project, GitHub language-bar style
+///
+/// # Arguments
+/// * `project_path` - Root path of the project
+///
+/// # Returns
+/// * `Result<LanguageBreakdown>` - Primary language and per-language byte shares
+///
+/// Bytes are tallied per extension over the same walk as `analyze_project_files`.
+/// When any programming language is present, markup, styling and configuration
+/// files are left out so they can't dominate; otherwise they are reported instead.
+#[napi]
+pub fn detect_primary_language(project_path: String) -> Result<LanguageBreakdown> {
+    let path = Path::new(&project_path);
+
+    if !path.is_dir() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Project path is not a directory: {}", project_path),
+        ));
+    }
+
+    let mut code_bytes: HashMap<&'static str, i64> = HashMap::new();
+    let mut other_bytes: HashMap<&'static str, i64> = HashMap::new();
+
+    for entry in project_walker(path).flatten() {
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+
+        let extension = entry
+            .path()
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        if let Some((language, is_code)) = language_for_extension(&extension) {
+            let size = entry.metadata().map(|m| m.len() as i64).unwrap_or(0);
+            let tally = if is_code { &mut code_bytes } else { &mut other_bytes };
+            *tally.entry(language).or_insert(0) += size;
+        }
+    }
+
+    let tally = if code_bytes.is_empty() { other_bytes } else { code_bytes };
+    let total: i64 = tally.values().sum();
+
+    let mut languages: Vec<LanguageStats> = tally
+        .into_iter()
+        .map(|(language, bytes)| LanguageStats {
+            language: language.to_string(),
+            bytes,
+            percentage: if total > 0 {
+                bytes as f64 * 100.0 / total as f64
+            } else {
+                0.0
+            },
+        })
+        .collect();
+
+    languages.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.language.cmp(&b.language)));
+
+    Ok(LanguageBreakdown {
+        primary_language: languages.first().map(|l| l.language.clone()),
+        languages,
+    })
+}
+
+/// Default number of entries in `ProjectStats::largest_files`
+const DEFAULT_LARGEST_FILES_LIMIT: u32 = 10;
+
+#[napi(object)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectStatsOptions {
+    /// Collect the biggest files (default: true)
+    pub include_largest_files: Option<bool>,
+    /// Number of largest files to keep (default: 10)
+    pub largest_files_limit: Option<u32>,
+    /// Break file counts and sizes down by extension (default: true)
+    pub include_extensions: Option<bool>,
+    /// Count lines of text files, which reads every file (default: false)
+    pub include_line_counts: Option<bool>,
+    /// Files larger than this are not read for line counts (default: 10 MiB)
+    pub max_file_size_bytes: Option<i64>,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileSizeEntry {
+    /// File path relative to the project root, with forward slashes
+    pub path: String,
+    pub size: i64,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtensionStats {
+    /// Lowercased extension without the dot, empty for files without one
+    pub extension: String,
+    pub file_count: u32,
+    pub total_size: i64,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectStats {
+    pub file_count: u32,
+    /// Directories below the project root
+    pub dir_count: u32,
+    pub total_size: i64,
+    /// Largest files, biggest first; `None` when disabled
+    pub largest_files: Option<Vec<FileSizeEntry>>,
+    /// Per-extension totals sorted by size, largest first; `None` when disabled
+    pub extensions: Option<Vec<ExtensionStats>>,
+    /// Lines across text files up to `max_file_size_bytes`; `None` when disabled
+    pub total_lines: Option<i64>,
+    /// Files skipped by the line count for exceeding the size limit
+    pub warnings: Vec<String>,
+}
+
+/// Add a file to its extension's totals
+fn tally_extension(extensions: &mut HashMap<String, ExtensionStats>, path: &Path, size: u64) {
+    let extension = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let tally = extensions.entry(extension.clone()).or_insert(ExtensionStats {
+        extension,
+        file_count: 0,
+        total_size: 0,
+    });
+    tally.file_count += 1;
+    tally.total_size += size as i64;
+}
+
+/// Extension totals sorted by size, largest first
+fn sorted_extension_stats(extensions: HashMap<String, ExtensionStats>) -> Vec<ExtensionStats> {
+    let mut extensions: Vec<ExtensionStats> = extensions.into_values().collect();
+    extensions.sort_by(|a, b| b.total_size.cmp(&a.total_size).then_with(|| a.extension.cmp(&b.extension)));
+    extensions
+}
+
+/// Count the lines of a text file's bytes, or `None` for binary content
+fn count_lines(bytes: &[u8]) -> Option<i64> {
+    if bytes.contains(&0) {
+        return None;
+    }
+
+    let newlines = bytes.iter().filter(|&&b| b == b'\n').count() as i64;
+    let unterminated = !bytes.is_empty() && !bytes.ends_with(b"\n");
+    Some(newlines + unterminated as i64)
+}
+
+/// Compute dashboard statistics for a project in a single traversal
+///
+/// # Arguments
+/// * `project_path` - Root path of the project
+/// * `options` - Optional toggles for the more expensive metrics (see `ProjectStatsOptions`)
+///
+/// # Returns
+/// * `Result<ProjectStats>` - File and directory counts, total size, and the enabled metrics.
+///   Uses the same walk as `analyze_project_files`, so ignored directories are skipped
+#[napi]
+pub fn compute_project_stats(project_path: String, options: Option<ProjectStatsOptions>) -> Result<ProjectStats> {
+    let path = Path::new(&project_path);
+
+    if !path.is_dir() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Project path is not a directory: {}", project_path),
+        ));
+    }
+
+    let options = options.unwrap_or_default();
+    let largest_limit = if options.include_largest_files.unwrap_or(true) {
+        Some(options.largest_files_limit.unwrap_or(DEFAULT_LARGEST_FILES_LIMIT) as usize)
+    } else {
+        None
+    };
+    let include_extensions = options.include_extensions.unwrap_or(true);
+    let include_line_counts = options.include_line_counts.unwrap_or(false);
+    let max_file_size = max_file_size(options.max_file_size_bytes, DEFAULT_MAX_FILE_SIZE_BYTES);
+
+    let mut stats = ProjectStats {
+        file_count: 0,
+        dir_count: 0,
+        total_size: 0,
+        largest_files: None,
+        extensions: None,
+        total_lines: include_line_counts.then_some(0),
+        warnings: Vec::new(),
+    };
+    // Min-heap of the largest files seen so far, so memory stays bounded by the limit
+    let mut largest: BinaryHeap<Reverse<(u64, PathBuf)>> = BinaryHeap::new();
+    let mut extensions: HashMap<String, ExtensionStats> = HashMap::new();
+
+    for entry in project_walker(path).flatten() {
+        if entry.file_type().is_some_and(|t| t.is_dir()) {
+            if entry.depth() > 0 {
+                stats.dir_count += 1;
+            }
+            continue;
+        }
+
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        stats.file_count += 1;
+        stats.total_size += size as i64;
+
+        if let Some(limit) = largest_limit {
+            largest.push(Reverse((size, entry.path().to_path_buf())));
+            if largest.len() > limit {
+                largest.pop();
+            }
+        }
+
+        if include_extensions {
+            tally_extension(&mut extensions, entry.path(), size);
+        }
+
+        if let Some(total_lines) = stats.total_lines.as_mut() {
+            if size > max_file_size {
+                stats.warnings.push(oversized_file_warning(entry.path(), max_file_size));
+            } else if let Some(lines) = fs::read(entry.path()).ok().and_then(|bytes| count_lines(&bytes)) {
+                *total_lines += lines;
+            }
+        }
+    }
+
+    if largest_limit.is_some() {
+        stats.largest_files = Some(
+            largest
+                .into_sorted_vec()
+                .into_iter()
+                .map(|Reverse((size, file_path))| FileSizeEntry {
+                    path: file_path
+                        .strip_prefix(path)
+                        .map(to_forward_slashes)
+                        .unwrap_or_default(),
+                    size: size as i64,
+                })
+                .collect(),
+        );
+    }
+
+    if include_extensions {
+        stats.extensions = Some(sorted_extension_stats(extensions));
+    }
+
+    Ok(stats)
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestInfo {
+    /// Path of the manifest, with forward slashes on every platform
+    pub path: String,
+    /// Ecosystem of the manifest: "npm", "cargo", "pypi", "rubygems", "go" or "composer"
+    pub manifest_type: String,
+}
+
+/// Map a manifest file name to its ecosystem
+fn manifest_type(file_name: &str) -> Option<&'static str> {
+    match file_name {
+        "package.json" => Some("npm"),
+        "Cargo.toml" => Some("cargo"),
+        "pyproject.toml" | "requirements.txt" | "setup.py" | "Pipfile" => Some("pypi"),
+        "Gemfile" => Some("rubygems"),
+        "go.mod" => Some("go"),
+        "composer.json" => Some("composer"),
+        _ => None,
+    }
+}
+
+/// Find every project manifest in the tree, including nested apps
+///
+/// # Arguments
+/// * `project_path` - Root path of the project to scan
+///
+/// # Returns
+/// * `Result<Vec<ManifestInfo>>` - Path and ecosystem of each manifest found, skipping ignored directories
+#[napi]
+pub fn find_all_manifests(project_path: String) -> Result<Vec<ManifestInfo>> {
+    let path = Path::new(&project_path);
+
+    if !path.is_dir() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Project path is not a directory: {}", project_path),
+        ));
+    }
+
+    let mut manifests = Vec::new();
+
+    for entry in project_walker(path).flatten() {
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+
+        let file_name = entry.file_name().to_string_lossy();
+        if let Some(manifest_type) = manifest_type(&file_name) {
+            manifests.push(ManifestInfo {
+                path: to_forward_slashes(entry.path()),
+                manifest_type: manifest_type.to_string(),
+            });
+        }
+    }
+
+    Ok(manifests)
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectRootInfo {
+    pub is_project: bool,
+    /// File names of the manifests found at the top level (e.g. "package.json")
+    pub manifests: Vec<String>,
+}
+
+/// Check whether a directory looks like a project root, without scanning its tree
+///
+/// # Arguments
+/// * `path` - Directory to check
+///
+/// # Returns
+/// * `Result<ProjectRootInfo>` - Whether any recognized manifest (package.json, Cargo.toml,
+///   pyproject.toml, go.mod, Gemfile, composer.json, ...) sits directly in the directory
+#[napi]
+pub fn is_project_root(path: String) -> Result<ProjectRootInfo> {
+    let dir = Path::new(&path);
+
+    if !dir.is_dir() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Path is not a directory: {}", path),
+        ));
+    }
+
+    let mut manifests: Vec<String> = fs::read_dir(dir)
+        .map_err(|e| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to read directory {}: {}", path, e),
+            )
+        })?
+        .flatten()
+        .filter(|entry| entry.file_type().map(|t| t.is_file()).unwrap_or(false))
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|file_name| manifest_type(file_name).is_some())
+        .collect();
+    manifests.sort();
+
+    Ok(ProjectRootInfo {
+        is_project: !manifests.is_empty(),
+        manifests,
+    })
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockfileFreshness {
+    pub manifest: String,
+    /// Most recently modified lockfile for the manifest, if any
+    pub lockfile: Option<String>,
+    /// true when the lockfile is missing or older than the manifest
+    pub lockfile_stale: bool,
+}
+
+/// Manifests and the lockfiles an install generates for them
+const MANIFEST_LOCKFILES: &[(&str, &[&str])] = &[
+    ("package.json", &["package-lock.json", "yarn.lock", "pnpm-lock.yaml", "bun.lockb"]),
+    ("Cargo.toml", &["Cargo.lock"]),
+    ("Gemfile", &["Gemfile.lock"]),
+    ("composer.json", &["composer.lock"]),
+];
+
+fn modified_time(path: &Path) -> Option<std::time::SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Check whether lockfiles are older than their manifests
+///
+/// # Arguments
+/// * `project_path` - Root path of the project
+///
+/// # Returns
+/// * `Result<Vec<LockfileFreshness>>` - One entry per manifest found at the project root
+#[napi]
+pub fn check_lockfile_freshness(project_path: String) -> Result<Vec<LockfileFreshness>> {
+    let path = Path::new(&project_path);
+
+    if !path.is_dir() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Project path is not a directory: {}", project_path),
+        ));
+    }
+
+    let mut results = Vec::new();
+
+    for (manifest, lockfiles) in MANIFEST_LOCKFILES {
+        let manifest_modified = match modified_time(&path.join(manifest)) {
+            Some(modified) => modified,
+            None => continue,
+        };
+
+        let newest_lockfile = lockfiles
+            .iter()
+            .filter_map(|lockfile| modified_time(&path.join(lockfile)).map(|modified| (*lockfile, modified)))
+            .max_by_key(|(_, modified)| *modified);
+
+        results.push(LockfileFreshness {
+            manifest: manifest.to_string(),
+            lockfile: newest_lockfile.map(|(lockfile, _)| lockfile.to_string()),
+            lockfile_stale: newest_lockfile.is_none_or(|(_, modified)| modified < manifest_modified),
+        });
+    }
+
+    Ok(results)
+}
+
+/// Bumped whenever `FileAnalysis` changes shape, so caches from older builds read as stale
+const ANALYSIS_CACHE_SCHEMA_VERSION: u32 = 1;
+
+#[napi(object)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnalysisCache {
+    /// Cache format version; caches written before versioning read as 0
+    pub schema_version: u32,
+    pub project_path: String,
+    pub analysis: FileAnalysis,
+    /// Milliseconds since the Unix epoch when the cache was written
+    pub created_at_ms: i64,
+    pub tree_hash: String,
+    /// Set when reading the cache if the project tree no longer matches `tree_hash`
+    #[serde(skip)]
+    pub is_stale: bool,
+}
+
+/// Run the analysis and write it to a JSON cache file
+///
+/// # Arguments
+/// * `project_path` - Root path of the project to analyze
+/// * `output_path` - Path of the JSON file to write
+///
+/// # Returns
+/// * `Result<AnalysisCache>` - The cached analysis, timestamp and tree hash
+#[napi]
+pub fn write_analysis_cache(project_path: String, output_path: String) -> Result<AnalysisCache> {
+    let analysis = analyze_project_files(project_path.clone(), None)?;
+    let tree_hash = compute_tree_hash(Path::new(&project_path));
+
+    let created_at_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0);
+
+    let cache = AnalysisCache {
+        schema_version: ANALYSIS_CACHE_SCHEMA_VERSION,
+        project_path,
+        analysis,
+        created_at_ms,
+        tree_hash,
+        is_stale: false,
+    };
+
+    let json = serde_json::to_string_pretty(&cache).map_err(|e| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Failed to serialize analysis cache: {}", e),
+        )
+    })?;
+
+    fs::write(&output_path, json).map_err(|e| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Failed to write analysis cache {}: {}", output_path, e),
+        )
+    })?;
+
+    Ok(cache)
+}
+
+/// Load an analysis cache file and check it against the current project tree
+///
+/// # Arguments
+/// * `output_path` - Path of the JSON file written by `write_analysis_cache`
+///
+/// # Returns
+/// * `Result<AnalysisCache>` - The cached analysis, with `is_stale` set if the tree hash no longer matches
+///
+/// # Behavior
+/// A cache that can't be parsed or was written with an older schema is returned as stale
+/// rather than as an error, so callers simply regenerate it.
+#[napi]
+pub fn read_analysis_cache(output_path: String) -> Result<AnalysisCache> {
+    let content = fs::read_to_string(&output_path).map_err(|e| {
+        Error::new(
+            Status::InvalidArg,
+            format!("Failed to read analysis cache {}: {}", output_path, e),
+        )
+    })?;
+
+    let Ok(mut cache) = serde_json::from_str::<AnalysisCache>(&content) else {
+        return Ok(AnalysisCache {
+            is_stale: true,
+            ..AnalysisCache::default()
+        });
+    };
+
+    let project_path = Path::new(&cache.project_path);
+    cache.is_stale = cache.schema_version != ANALYSIS_CACHE_SCHEMA_VERSION
+        || !project_path.is_dir()
+        || compute_tree_hash(project_path) != cache.tree_hash;
+
+    Ok(cache)
+}
+
+/// Hash relative paths, sizes and modification times of all project files (FNV-1a, stable across runs)
+fn compute_tree_hash(path: &Path) -> String {
+    const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    let mut hash = FNV_OFFSET;
+    let mut feed = |bytes: &[u8]| {
+        for byte in bytes {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    };
+
+    for entry in project_walker(path).flatten() {
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+
+        let relative = entry.path().strip_prefix(path).unwrap_or(entry.path());
+        feed(relative.to_string_lossy().as_bytes());
+
+        if let Ok(metadata) = entry.metadata() {
+            feed(&metadata.len().to_le_bytes());
+
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_nanos())
+                .unwrap_or(0);
+            feed(&modified.to_le_bytes());
+        }
+    }
+
+    format!("{:016x}", hash)
+}
+
+/// Get the default build output directory for common frameworks
+///
+/// # Arguments
+/// * `framework` - Framework name (e.g., "react", "next", "vite"), as accepted by `get_default_port`
+///
+/// # Returns
+/// * `Result<String>` - Output directory relative to the project root; errors for unknown
+///   frameworks and for servers without a build step (e.g. "express")
+#[napi]
+pub fn get_framework_output_dir(framework: String) -> Result<String> {
+    let framework_lower = framework.to_lowercase();
+
+    let output_dir = match framework_lower.as_str() {
+        "react" | "create-react-app" | "cra" => "build",
+        "next" | "nextjs" | "next.js" => ".next",
+        "vite" | "vitejs" => "dist",
+        "vue" | "vuejs" => "dist",
+        "angular" => "dist",
+        "svelte" | "sveltekit" => "build",
+        "nuxt" | "nuxtjs" => ".output",
+        "nest" | "nestjs" => "dist",
+        "gatsby" => "public",
+        "remix" => "build",
+        "astro" => "dist",
+        _ => {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!("Unknown framework: {}", framework),
+            ));
+        }
+    };
+
+    Ok(output_dir.to_string())
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileNode {
+    pub name: String,
+    pub path: String,
+    pub r#type: String,
+    pub children: Option<Vec<FileNode>>,
+    /// Hex digest of the file contents, only set for files when hashing was requested
+    pub hash: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileTree {
+    pub nodes: Vec<FileNode>,
+    /// Folders within the depth limit whose contents couldn't be read (e.g. macOS-protected folders)
+    pub permission_denied_paths: Vec<String>,
+    /// Files left without a hash for exceeding the size limit
+    pub warnings: Vec<String>,
+}
+
+/// Settings and findings shared across one `list_file_tree` traversal
+struct TreeScan {
+    hash: Option<HashAlgorithm>,
+    max_file_size: u64,
+    permission_denied_paths: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl TreeScan {
+    fn new(hash: Option<HashAlgorithm>, max_file_size: u64) -> Self {
+        TreeScan {
+            hash,
+            max_file_size,
+            permission_denied_paths: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Hash a file if hashing was requested, warning instead when it is over the size limit
+    fn hash(&mut self, path: &Path) -> Option<String> {
+        let algorithm = self.hash?;
+        if fs::metadata(path).ok()?.len() > self.max_file_size {
+            self.warnings.push(oversized_file_warning(path, self.max_file_size));
+            return None;
+        }
+
+        hash_file(path, algorithm)
+    }
+}
+
+/// Content hash algorithms supported by `list_project_files`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HashAlgorithm {
+    Sha256,
+}
+
+fn parse_hash_algorithm(with_hash: Option<&str>) -> Result<Option<HashAlgorithm>> {
+    match with_hash.map(|name| name.to_ascii_lowercase()) {
+        None => Ok(None),
+        Some(name) if name == "sha256" => Ok(Some(HashAlgorithm::Sha256)),
+        Some(name) => Err(Error::new(
+            Status::InvalidArg,
+            format!("Unsupported hash algorithm: {} (expected \"sha256\")", name),
+        )),
+    }
+}
+
+/// Hash a file's contents, streaming so large files are never fully buffered.
+/// Files that can't be read get no hash.
+fn hash_file(path: &Path, algorithm: HashAlgorithm) -> Option<String> {
+    let mut file = fs::File::open(path).ok()?;
+    match algorithm {
+        HashAlgorithm::Sha256 => {
+            let mut hasher = Sha256::new();
+            std::io::copy(&mut file, &mut hasher).ok()?;
+            Some(format!("{:x}", hasher.finalize()))
+        }
+    }
+}
+
+/// List project files in tree structure for file explorer
+///
+/// # Arguments
+/// * `project_path` - Root path of the project
+/// * `max_depth` - Maximum depth to traverse (default: 3)
+/// * `with_hash` - Optional hash algorithm ("sha256") to fill each file's `hash`;
+///   prefer `list_project_files_async` when hashing, as it reads every file
+/// * `max_file_size_bytes` - Files larger than this are not hashed (default: 10 MiB)
+///
+/// # Returns
+/// * `Result<FileTree>` - Tree structure of files and folders, plus the folders that
+///   couldn't be read and the files too large to hash; node paths are canonical and use
+///   forward slashes (see `normalize_path`)
+#[napi]
+pub fn list_project_files(
+    project_path: String,
+    max_depth: Option<u32>,
+    with_hash: Option<String>,
+    max_file_size_bytes: Option<i64>,
+) -> Result<FileTree> {
+    let hash = parse_hash_algorithm(with_hash.as_deref())?;
+    let max_file_size = max_file_size(max_file_size_bytes, DEFAULT_MAX_FILE_SIZE_BYTES);
+    list_file_tree(&project_path, max_depth.unwrap_or(3), TreeScan::new(hash, max_file_size))
+}
+
+pub struct ListFilesTask {
+    project_path: String,
+    max_depth: u32,
+    hash: Option<HashAlgorithm>,
+    max_file_size: u64,
+}
+
+impl Task for ListFilesTask {
+    type Output = FileTree;
+    type JsValue = FileTree;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        list_file_tree(&self.project_path, self.max_depth, TreeScan::new(self.hash, self.max_file_size))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// List project files in tree structure, off the main thread
+///
+/// # Arguments
+/// * `project_path` - Root path of the project
+/// * `max_depth` - Maximum depth to traverse (default: 3)
+/// * `with_hash` - Optional hash algorithm ("sha256") to fill each file's `hash`
+/// * `max_file_size_bytes` - Files larger than this are not hashed (default: 10 MiB)
+///
+/// # Returns
+/// * `Promise<FileTree>` - Same tree as `list_project_files`
+#[napi]
+pub fn list_project_files_async(
+    project_path: String,
+    max_depth: Option<u32>,
+    with_hash: Option<String>,
+    max_file_size_bytes: Option<i64>,
+) -> Result<AsyncTask<ListFilesTask>> {
+    let hash = parse_hash_algorithm(with_hash.as_deref())?;
+
+    Ok(AsyncTask::new(ListFilesTask {
+        project_path,
+        max_depth: max_depth.unwrap_or(3),
+        hash,
+        max_file_size: max_file_size(max_file_size_bytes, DEFAULT_MAX_FILE_SIZE_BYTES),
+    }))
+}
+
+/// Collect every file under the project, relative to its root with forward slashes
+fn collect_all_files(project_path: &str) -> Result<Vec<String>> {
+    let path = Path::new(project_path);
+
+    if !path.is_dir() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Project path is not a directory: {}", project_path),
+        ));
+    }
+
+    let files = project_walker(path)
+        .flatten()
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+        .filter_map(|entry| {
+            entry
+                .path()
+                .strip_prefix(path)
+                .ok()
+                .map(to_forward_slashes)
+        })
+        .collect();
+
+    Ok(files)
+}
+
+pub struct ListAllFilesTask {
+    project_path: String,
+}
+
+impl Task for ListAllFilesTask {
+    type Output = Vec<String>;
+    type JsValue = Vec<String>;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        collect_all_files(&self.project_path)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// List every file in the project as a flat list, off the main thread
+///
+/// # Arguments
+/// * `project_path` - Root path of the project
+///
+/// # Returns
+/// * `Promise<string[]>` - File paths relative to the root, using forward slashes, in
+///   name-sorted walk order. Skips the same directories as `analyze_project_files`
+#[napi]
+pub fn list_all_files(project_path: String) -> AsyncTask<ListAllFilesTask> {
+    AsyncTask::new(ListAllFilesTask { project_path })
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RenderTreeOptions {
+    /// Wrap the tree in a fenced markdown code block (default: false)
+    pub markdown: Option<bool>,
+}
+
+/// Append the `├──`/`└──` lines for `nodes` and their descendants
+fn render_tree_nodes(nodes: &[FileNode], prefix: &str, output: &mut String) {
+    for (index, node) in nodes.iter().enumerate() {
+        let is_last = index + 1 == nodes.len();
+        output.push_str(prefix);
+        output.push_str(if is_last { "└── " } else { "├── " });
+        output.push_str(&node.name);
+        output.push('\n');
 
-                // Extract devDependencies
-                if let Some(dev_deps) = package_json.get("devDependencies").and_then(|d| d.as_object()) {
-                    for key in dev_deps.keys() {
-                        if !analysis.dependencies.contains(key) {
-                            analysis.dependencies.push(key.clone());
-                        }
-                    }
-                }
-            }
+        if let Some(children) = &node.children {
+            let child_prefix = format!("{}{}", prefix, if is_last { "    " } else { "│   " });
+            render_tree_nodes(children, &child_prefix, output);
         }
     }
+}
 
-    // Walk directory tree respecting .gitignore
-    let walker = WalkDir::new(path)
-        .follow_links(false)
-        .into_iter()
-        .filter_entry(|e| {
-            let path = e.path();
-            let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-
-            // Skip common directories that should be ignored
-            !matches!(
-                file_name,
-                "node_modules" | ".git" | "target" | "dist" | "build" | ".next" | "out" | "__pycache__" | ".venv" | "venv"
-            )
-        });
-
-    for entry in walker {
-        match entry {
-            Ok(entry) => {
-                if entry.file_type().is_file() {
-                    analysis.file_count += 1;
+/// Attach each folder's walked entries as its children, sorted folders first then by name
+fn assemble_tree_nodes(parent: &Path, children_by_parent: &mut HashMap<PathBuf, Vec<FileNode>>) -> Vec<FileNode> {
+    let mut nodes = children_by_parent.remove(parent).unwrap_or_default();
 
-                    if let Ok(metadata) = entry.metadata() {
-                        analysis.total_size += metadata.len() as i64;
-                    }
-                }
-            }
-            Err(_) => {
-                // Skip entries that can't be read (permission issues, etc.)
-                continue;
-            }
-        }
+    for node in nodes.iter_mut().filter(|node| node.r#type == "folder") {
+        let folder = parent.join(&node.name);
+        node.children = Some(assemble_tree_nodes(&folder, children_by_parent));
     }
 
-    Ok(analysis)
-}
+    nodes.sort_by(|a, b| {
+        match (a.r#type.as_str(), b.r#type.as_str()) {
+            ("folder", "file") => std::cmp::Ordering::Less,
+            ("file", "folder") => std::cmp::Ordering::Greater,
+            _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        }
+    });
 
-#[napi(object)]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FileNode {
-    pub name: String,
-    pub path: String,
-    pub r#type: String,
-    pub children: Option<Vec<FileNode>>,
+    nodes
 }
 
-/// List project files in tree structure for file explorer
+/// Render the project's file tree as text, like the `tree` command
 ///
 /// # Arguments
 /// * `project_path` - Root path of the project
 /// * `max_depth` - Maximum depth to traverse (default: 3)
+/// * `options` - Optional output settings (see `RenderTreeOptions`)
 ///
 /// # Returns
-/// * `Result<Vec<FileNode>>` - Tree structure of files and folders
+/// * `Result<String>` - The project folder name followed by one line per entry, folders
+///   first and then alphabetically. Skips the same files as `analyze_project_files`,
+///   including those matched by `.gitignore`
 #[napi]
-pub fn list_project_files(project_path: String, max_depth: Option<u32>) -> Result<Vec<FileNode>> {
-    let path = Path::new(&project_path);
-    let depth_limit = max_depth.unwrap_or(3);
+pub fn render_tree_text(project_path: String, max_depth: Option<u32>, options: Option<RenderTreeOptions>) -> Result<String> {
+    let root = Path::new(&project_path);
+    if !root.is_dir() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Project path is not a directory: {}", project_path),
+        ));
+    }
+
+    let markdown = options.unwrap_or_default().markdown.unwrap_or(false);
+    let walk = project_walk_builder(root, true)
+        .max_depth(Some(max_depth.unwrap_or(3) as usize))
+        .build();
+
+    // Group entries under their parent folder, then assemble the tree from the root down
+    let mut children_by_parent: HashMap<PathBuf, Vec<FileNode>> = HashMap::new();
+    for entry in walk.flatten() {
+        let (Some(file_type), Some(parent)) = (entry.file_type(), entry.path().parent()) else {
+            continue;
+        };
+        if entry.depth() == 0 || !(file_type.is_dir() || file_type.is_file()) {
+            continue;
+        }
+
+        children_by_parent.entry(parent.to_path_buf()).or_default().push(FileNode {
+            name: entry.file_name().to_string_lossy().to_string(),
+            path: to_forward_slashes(entry.path()),
+            r#type: if file_type.is_dir() { "folder" } else { "file" }.to_string(),
+            children: None,
+            hash: None,
+        });
+    }
+    let nodes = assemble_tree_nodes(root, &mut children_by_parent);
+
+    let root_name = root
+        .canonicalize()
+        .unwrap_or_else(|_| root.to_path_buf())
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| ".".to_string());
+
+    let mut output = String::new();
+    if markdown {
+        output.push_str("```\n");
+    }
+    output.push_str(&root_name);
+    output.push('\n');
+    render_tree_nodes(&nodes, "", &mut output);
+    if markdown {
+        output.push_str("```\n");
+    }
+
+    Ok(output)
+}
+
+fn list_file_tree(project_path: &str, depth_limit: u32, mut scan: TreeScan) -> Result<FileTree> {
+    let path = Path::new(project_path);
 
     if !path.exists() {
         return Err(Error::new(
@@ -161,8 +1569,11 @@ pub fn list_project_files(project_path: String, max_depth: Option<u32>) -> Resul
 
     let mut root_nodes = Vec::new();
 
+    // Canonicalize once so every node path has a single string form
+    let root = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+
     // Read immediate children of root directory
-    if let Ok(entries) = fs::read_dir(path) {
+    if let Ok(entries) = fs::read_dir(&root) {
         for entry in entries.flatten() {
             if let Ok(file_type) = entry.file_type() {
                 let file_name = entry.file_name().to_string_lossy().to_string();
@@ -176,11 +1587,11 @@ pub fn list_project_files(project_path: String, max_depth: Option<u32>) -> Resul
                     continue;
                 }
 
-                let node_path = entry.path().to_string_lossy().to_string();
+                let node_path = to_forward_slashes(&entry.path());
 
                 if file_type.is_dir() {
                     let children = if depth_limit > 1 {
-                        Some(read_directory(&entry.path(), 1, depth_limit)?)
+                        Some(read_directory(&entry.path(), 1, depth_limit, &mut scan)?)
                     } else {
                         Some(Vec::new())
                     };
@@ -190,6 +1601,7 @@ pub fn list_project_files(project_path: String, max_depth: Option<u32>) -> Resul
                         path: node_path,
                         r#type: "folder".to_string(),
                         children,
+                        hash: None,
                     });
                 } else if file_type.is_file() {
                     root_nodes.push(FileNode {
@@ -197,6 +1609,7 @@ pub fn list_project_files(project_path: String, max_depth: Option<u32>) -> Resul
                         path: node_path,
                         r#type: "file".to_string(),
                         children: None,
+                        hash: scan.hash(&entry.path()),
                     });
                 }
             }
@@ -212,17 +1625,85 @@ pub fn list_project_files(project_path: String, max_depth: Option<u32>) -> Resul
         }
     });
 
-    Ok(root_nodes)
+    Ok(FileTree {
+        nodes: root_nodes,
+        permission_denied_paths: scan.permission_denied_paths,
+        warnings: scan.warnings,
+    })
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectoryPage {
+    pub nodes: Vec<FileNode>,
+    /// Number of visible entries in the directory, across all pages
+    pub total: u32,
+    pub has_more: bool,
+}
+
+/// List the immediate children of a directory, one page at a time
+///
+/// # Arguments
+/// * `dir_path` - Directory to list
+/// * `offset` - Number of entries to skip (default: 0)
+/// * `limit` - Maximum number of entries to return (default: all)
+///
+/// # Returns
+/// * `Result<DirectoryPage>` - Page of nodes plus the total count
+///
+/// Entries are filtered and sorted exactly like `list_project_files` (folders
+/// first, then alphabetically) before paginating, so pages are stable. Folder
+/// nodes have empty `children`; list them with another call when expanded.
+#[napi]
+pub fn list_directory_children(dir_path: String, offset: Option<u32>, limit: Option<u32>) -> Result<DirectoryPage> {
+    let path = PathBuf::from(&dir_path);
+
+    if !path.is_dir() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Path is not a directory: {}", dir_path),
+        ));
+    }
+
+    let path = path.canonicalize().unwrap_or(path);
+    let nodes = read_directory(&path, 0, 1, &mut TreeScan::new(None, DEFAULT_MAX_FILE_SIZE_BYTES))?;
+    let total = nodes.len();
+    let start = (offset.unwrap_or(0) as usize).min(total);
+    let end = match limit {
+        Some(limit) => start.saturating_add(limit as usize).min(total),
+        None => total,
+    };
+
+    Ok(DirectoryPage {
+        nodes: nodes[start..end].to_vec(),
+        total: total as u32,
+        has_more: end < total,
+    })
 }
 
-fn read_directory(path: &PathBuf, current_depth: u32, max_depth: u32) -> Result<Vec<FileNode>> {
+fn read_directory(
+    path: &PathBuf,
+    current_depth: u32,
+    max_depth: u32,
+    scan: &mut TreeScan,
+) -> Result<Vec<FileNode>> {
     let mut nodes = Vec::new();
 
     if current_depth >= max_depth {
         return Ok(nodes);
     }
 
-    if let Ok(entries) = fs::read_dir(path) {
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => Some(entries),
+        Err(e) => {
+            if e.kind() == std::io::ErrorKind::PermissionDenied {
+                scan.permission_denied_paths.push(to_forward_slashes(path));
+            }
+            None
+        }
+    };
+
+    if let Some(entries) = entries {
         for entry in entries.flatten() {
             if let Ok(file_type) = entry.file_type() {
                 let file_name = entry.file_name().to_string_lossy().to_string();
@@ -236,11 +1717,11 @@ fn read_directory(path: &PathBuf, current_depth: u32, max_depth: u32) -> Result<
                     continue;
                 }
 
-                let node_path = entry.path().to_string_lossy().to_string();
+                let node_path = to_forward_slashes(&entry.path());
 
                 if file_type.is_dir() {
                     let children = if current_depth + 1 < max_depth {
-                        Some(read_directory(&entry.path(), current_depth + 1, max_depth)?)
+                        Some(read_directory(&entry.path(), current_depth + 1, max_depth, scan)?)
                     } else {
                         Some(Vec::new())
                     };
@@ -250,6 +1731,7 @@ fn read_directory(path: &PathBuf, current_depth: u32, max_depth: u32) -> Result<
                         path: node_path,
                         r#type: "folder".to_string(),
                         children,
+                        hash: None,
                     });
                 } else if file_type.is_file() {
                     nodes.push(FileNode {
@@ -257,6 +1739,7 @@ fn read_directory(path: &PathBuf, current_depth: u32, max_depth: u32) -> Result<
                         path: node_path,
                         r#type: "file".to_string(),
                         children: None,
+                        hash: scan.hash(&entry.path()),
                     });
                 }
             }
@@ -281,7 +1764,7 @@ mod tests {
 
     #[test]
     fn test_invalid_path() {
-        let result = analyze_project_files("/nonexistent/path/12345".to_string());
+        let result = analyze_project_files("/nonexistent/path/12345".to_string(), None);
         assert!(result.is_err());
     }
 
@@ -291,10 +1774,654 @@ mod tests {
         let temp_file = std::env::temp_dir().join("test_file.txt");
         std::fs::write(&temp_file, "test").unwrap();
 
-        let result = analyze_project_files(temp_file.to_string_lossy().to_string());
+        let result = analyze_project_files(temp_file.to_string_lossy().to_string(), None);
         assert!(result.is_err());
 
         // Cleanup
         let _ = std::fs::remove_file(temp_file);
     }
+
+    #[test]
+    fn test_analyze_subdirectory_with_root_manifest() {
+        let project_dir = std::env::temp_dir().join("through_test_root_manifest");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(project_dir.join("packages/app")).unwrap();
+        std::fs::write(project_dir.join("package.json"), r#"{"dependencies":{"react":"^18.2.0"}}"#).unwrap();
+        std::fs::write(project_dir.join("packages/app/index.js"), "").unwrap();
+
+        let options = AnalyzeOptions {
+            root_manifest_path: Some(project_dir.join("package.json").to_string_lossy().to_string()),
+            ..Default::default()
+        };
+        let analysis = analyze_project_files(
+            project_dir.join("packages/app").to_string_lossy().to_string(),
+            Some(options),
+        )
+        .unwrap();
+        assert_eq!(analysis.file_count, 1);
+        assert_eq!(analysis.dependencies, vec!["react".to_string()]);
+
+        // Non-npm root manifests use their own parser
+        std::fs::write(project_dir.join("Cargo.toml"), "[dependencies]\nserde = \"1\"\n").unwrap();
+        let options = AnalyzeOptions {
+            root_manifest_path: Some(project_dir.join("Cargo.toml").to_string_lossy().to_string()),
+            ..Default::default()
+        };
+        let analysis = analyze_project_files(
+            project_dir.join("packages/app").to_string_lossy().to_string(),
+            Some(options),
+        )
+        .unwrap();
+        assert_eq!(analysis.dependencies, vec!["serde".to_string()]);
+
+        std::fs::write(project_dir.join("Gemfile"), "gem 'rails'\n").unwrap();
+        let options = AnalyzeOptions {
+            root_manifest_path: Some(project_dir.join("Gemfile").to_string_lossy().to_string()),
+            ..Default::default()
+        };
+        let result = analyze_project_files(project_dir.join("packages/app").to_string_lossy().to_string(), Some(options));
+        assert!(result.is_err());
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_oversized_files_are_reported() {
+        let project_dir = std::env::temp_dir().join("through_test_oversized_files");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(project_dir.join("small.txt"), "ok").unwrap();
+        std::fs::write(project_dir.join("large.bin"), vec![0u8; 2048]).unwrap();
+
+        let options = AnalyzeOptions {
+            max_file_size_bytes: Some(1024),
+            ..Default::default()
+        };
+        let analysis = analyze_project_files(project_dir.to_string_lossy().to_string(), Some(options)).unwrap();
+        assert_eq!(analysis.file_count, 2);
+        assert_eq!(analysis.total_size, 2050);
+        // Large files that are only counted, never read, don't need a warning
+        assert!(analysis.warnings.is_empty());
+
+        let padding = " ".repeat(2048);
+        std::fs::write(project_dir.join("package.json"), format!("{{{}}}", padding)).unwrap();
+        let options = AnalyzeOptions {
+            max_file_size_bytes: Some(1024),
+            ..Default::default()
+        };
+        let analysis = analyze_project_files(project_dir.to_string_lossy().to_string(), Some(options)).unwrap();
+        assert_eq!(analysis.warnings.len(), 1);
+        assert!(analysis.warnings[0].contains("package.json"));
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_detect_tests_and_framework() {
+        let project_dir = std::env::temp_dir().join("through_test_detect_tests");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(project_dir.join("src")).unwrap();
+        std::fs::write(project_dir.join("package.json"), r#"{"devDependencies":{"vitest":"^1.0.0"}}"#).unwrap();
+        std::fs::write(project_dir.join("src/app.ts"), "").unwrap();
+
+        let project_path = project_dir.to_string_lossy().to_string();
+        let analysis = analyze_project_files(project_path.clone(), None).unwrap();
+        assert_eq!(analysis.test_framework.as_deref(), Some("vitest"));
+        assert!(!analysis.has_tests);
+
+        std::fs::write(project_dir.join("src/app.test.ts"), "").unwrap();
+        let analysis = analyze_project_files(project_path, None).unwrap();
+        assert!(analysis.has_tests);
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_detect_primary_language_ignores_markup() {
+        let project_dir = std::env::temp_dir().join("through_test_primary_language");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(project_dir.join("main.rs"), "fn main() {}").unwrap();
+        std::fs::write(project_dir.join("README.md"), "#".repeat(1000)).unwrap();
+
+        let breakdown = detect_primary_language(project_dir.to_string_lossy().to_string()).unwrap();
+        assert_eq!(breakdown.primary_language.as_deref(), Some("Rust"));
+        assert_eq!(breakdown.languages.len(), 1);
+        assert_eq!(breakdown.languages[0].percentage, 100.0);
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_find_all_manifests_nested() {
+        let project_dir = std::env::temp_dir().join("through_test_find_manifests");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(project_dir.join("apps/web")).unwrap();
+        std::fs::create_dir_all(project_dir.join("services/api")).unwrap();
+        std::fs::create_dir_all(project_dir.join("node_modules/dep")).unwrap();
+        std::fs::write(project_dir.join("apps/web/package.json"), "{}").unwrap();
+        std::fs::write(project_dir.join("services/api/Cargo.toml"), "").unwrap();
+        std::fs::write(project_dir.join("node_modules/dep/package.json"), "{}").unwrap();
+
+        let manifests = find_all_manifests(project_dir.to_string_lossy().to_string()).unwrap();
+        let types: Vec<&str> = manifests.iter().map(|m| m.manifest_type.as_str()).collect();
+        assert_eq!(types, vec!["npm", "cargo"]);
+        assert!(manifests[0].path.ends_with("/apps/web/package.json"));
+        assert!(manifests.iter().all(|m| !m.path.contains('\\')));
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_check_lockfile_freshness() {
+        let project_dir = std::env::temp_dir().join("through_test_lockfile_freshness");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(project_dir.join("yarn.lock"), "").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        std::fs::write(project_dir.join("package.json"), "{}").unwrap();
+        std::fs::write(project_dir.join("Cargo.toml"), "").unwrap();
+
+        let results = check_lockfile_freshness(project_dir.to_string_lossy().to_string()).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].lockfile.as_deref(), Some("yarn.lock"));
+        assert!(results[0].lockfile_stale);
+        assert_eq!(results[1].lockfile, None);
+        assert!(results[1].lockfile_stale);
+
+        std::fs::write(project_dir.join("yarn.lock"), "# updated").unwrap();
+        let results = check_lockfile_freshness(project_dir.to_string_lossy().to_string()).unwrap();
+        assert!(!results[0].lockfile_stale);
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_list_directory_children_pagination() {
+        let dir = std::env::temp_dir().join("through_test_directory_page");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("b_folder")).unwrap();
+        for name in ["a.txt", "c.txt", "B.txt"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        let dir_path = dir.to_string_lossy().to_string();
+
+        let first = list_directory_children(dir_path.clone(), None, Some(2)).unwrap();
+        let names: Vec<&str> = first.nodes.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, vec!["b_folder", "a.txt"]);
+        assert_eq!(first.total, 4);
+        assert!(first.has_more);
+
+        let last = list_directory_children(dir_path, Some(2), Some(2)).unwrap();
+        let names: Vec<&str> = last.nodes.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, vec!["B.txt", "c.txt"]);
+        assert!(!last.has_more);
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_analysis_cache_roundtrip_and_staleness() {
+        let project_dir = std::env::temp_dir().join("through_test_analysis_cache");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(project_dir.join("index.js"), "console.log(1);").unwrap();
+        let cache_file = std::env::temp_dir().join("through_test_analysis_cache.json");
+        let cache_path = cache_file.to_string_lossy().to_string();
+
+        let written = write_analysis_cache(project_dir.to_string_lossy().to_string(), cache_path.clone()).unwrap();
+        let read = read_analysis_cache(cache_path.clone()).unwrap();
+        assert_eq!(read.tree_hash, written.tree_hash);
+        assert_eq!(read.analysis.file_count, 1);
+        assert!(!read.is_stale);
+
+        std::fs::write(project_dir.join("other.js"), "console.log(2);").unwrap();
+        let read = read_analysis_cache(cache_path.clone()).unwrap();
+        assert!(read.is_stale);
+
+        // A cache from a build without `schema_version` or newer fields loads as stale
+        let written = write_analysis_cache(project_dir.to_string_lossy().to_string(), cache_path.clone()).unwrap();
+        let legacy = serde_json::json!({
+            "project_path": written.project_path,
+            "analysis": { "has_package_json": false, "file_count": 2 },
+            "created_at_ms": written.created_at_ms,
+            "tree_hash": written.tree_hash,
+        });
+        std::fs::write(&cache_file, legacy.to_string()).unwrap();
+        let read = read_analysis_cache(cache_path.clone()).unwrap();
+        assert!(read.is_stale);
+        assert_eq!(read.analysis.file_count, 2);
+
+        std::fs::write(&cache_file, "{ not json").unwrap();
+        assert!(read_analysis_cache(cache_path).unwrap().is_stale);
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+        let _ = std::fs::remove_file(cache_file);
+    }
+
+    #[test]
+    fn test_list_project_files_with_hash() {
+        let project_dir = std::env::temp_dir().join("through_test_file_hashes");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(project_dir.join("assets")).unwrap();
+        std::fs::write(project_dir.join("a.txt"), "abc").unwrap();
+        std::fs::write(project_dir.join("assets/b.txt"), "abc").unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        let plain = list_project_files(project_path.clone(), None, None, None).unwrap().nodes;
+        assert!(plain.iter().all(|node| node.hash.is_none()));
+
+        let nodes = list_project_files(project_path.clone(), None, Some("sha256".to_string()), None).unwrap().nodes;
+        let sha_abc = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        assert_eq!(nodes[0].name, "assets");
+        assert!(nodes[0].hash.is_none());
+        assert_eq!(nodes[0].children.as_ref().unwrap()[0].hash.as_deref(), Some(sha_abc));
+        assert_eq!(nodes[1].hash.as_deref(), Some(sha_abc));
+
+        let capped = list_project_files(project_path.clone(), None, Some("sha256".to_string()), Some(2)).unwrap();
+        assert!(capped.nodes[1].hash.is_none());
+        assert_eq!(capped.warnings.len(), 2);
+
+        assert!(list_project_files(project_path, None, Some("crc32".to_string()), None).is_err());
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_parse_tool_versions() {
+        let content = "# runtimes\nnodejs 20.11.0\npython 3.12.1 3.11.7 # fallback\n\n   \nruby\n";
+        assert_eq!(
+            parse_tool_versions(content),
+            vec![
+                ToolVersion { tool: "nodejs".to_string(), version: "20.11.0".to_string() },
+                ToolVersion { tool: "python".to_string(), version: "3.12.1".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collect_all_files_relative_and_skipped() {
+        let project_dir = std::env::temp_dir().join("through_test_all_files");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(project_dir.join("src/nested")).unwrap();
+        std::fs::create_dir_all(project_dir.join("node_modules/react")).unwrap();
+        std::fs::write(project_dir.join("package.json"), "{}").unwrap();
+        std::fs::write(project_dir.join("src/nested/app.js"), "").unwrap();
+        std::fs::write(project_dir.join("node_modules/react/index.js"), "").unwrap();
+
+        let files = collect_all_files(&project_dir.to_string_lossy()).unwrap();
+        assert_eq!(files, vec!["package.json", "src/nested/app.js"]);
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_is_project_root() {
+        let project_dir = std::env::temp_dir().join("through_test_project_root");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(project_dir.join("docs")).unwrap();
+        std::fs::write(project_dir.join("go.mod"), "module example.com/app\n").unwrap();
+        std::fs::write(project_dir.join("package.json"), "{}").unwrap();
+
+        let info = is_project_root(project_dir.to_string_lossy().to_string()).unwrap();
+        assert!(info.is_project);
+        assert_eq!(info.manifests, vec!["go.mod", "package.json"]);
+
+        let docs = is_project_root(project_dir.join("docs").to_string_lossy().to_string()).unwrap();
+        assert!(!docs.is_project);
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_analyze_go_and_composer_projects() {
+        let project_dir = std::env::temp_dir().join("through_test_go_composer");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(
+            project_dir.join("go.mod"),
+            "module example.com/app\n\nrequire (\n\tgithub.com/gin-gonic/gin v1.9.1\n)\n",
+        )
+        .unwrap();
+        std::fs::write(project_dir.join("composer.json"), r#"{"require":{"php":">=8.1","monolog/monolog":"^3.0"}}"#).unwrap();
+
+        let analysis = analyze_project_files(project_dir.to_string_lossy().to_string(), None).unwrap();
+        assert!(analysis.has_go_mod);
+        assert!(analysis.has_composer_json);
+        assert_eq!(analysis.go_module.as_deref(), Some("example.com/app"));
+        assert_eq!(analysis.dependencies, vec!["github.com/gin-gonic/gin", "monolog/monolog"]);
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_find_todo_comments() {
+        let project_dir = std::env::temp_dir().join("through_test_todo_comments");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(project_dir.join("src")).unwrap();
+        std::fs::write(
+            project_dir.join("src/main.rs"),
+            "fn main() {\n    // TODO: handle errors\n    /* FIXME(jane) leaks */\n    let todos = 1; // not a TODOlist\n}\n",
+        )
+        .unwrap();
+        std::fs::write(project_dir.join("logo.png"), [0x89, b'P', b'N', b'G', 0, b'T', b'O', b'D', b'O']).unwrap();
+
+        let search = find_todo_comments(project_dir.to_string_lossy().to_string(), None, None).unwrap();
+        assert!(search.warnings.is_empty());
+        assert_eq!(
+            search.items,
+            vec![
+                TodoItem { path: "src/main.rs".to_string(), line: 2, kind: "TODO".to_string(), text: "handle errors".to_string() },
+                TodoItem { path: "src/main.rs".to_string(), line: 3, kind: "FIXME".to_string(), text: "(jane) leaks".to_string() },
+            ]
+        );
+        assert_eq!(find_todo_comments(project_dir.to_string_lossy().to_string(), Some(1), None).unwrap().items.len(), 1);
+
+        let capped = find_todo_comments(project_dir.to_string_lossy().to_string(), None, Some(16)).unwrap();
+        assert!(capped.items.is_empty());
+        assert_eq!(capped.warnings.len(), 1);
+        assert!(capped.warnings[0].contains("main.rs"));
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_get_framework_output_dir() {
+        assert_eq!(get_framework_output_dir("Next".to_string()).unwrap(), ".next");
+        assert_eq!(get_framework_output_dir("cra".to_string()).unwrap(), "build");
+        assert_eq!(get_framework_output_dir("gatsby".to_string()).unwrap(), "public");
+        assert!(get_framework_output_dir("express".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_compute_project_stats() {
+        let project_dir = std::env::temp_dir().join("through_test_project_stats");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(project_dir.join("src/nested")).unwrap();
+        std::fs::create_dir_all(project_dir.join("node_modules/pkg")).unwrap();
+        std::fs::write(project_dir.join("src/main.ts"), "one\ntwo\nthree").unwrap();
+        std::fs::write(project_dir.join("src/nested/util.ts"), "a\n").unwrap();
+        std::fs::write(project_dir.join("README.md"), "# readme\n\n").unwrap();
+        std::fs::write(project_dir.join("node_modules/pkg/index.js"), "ignored").unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        let stats = compute_project_stats(
+            project_path.clone(),
+            Some(ProjectStatsOptions {
+                largest_files_limit: Some(2),
+                include_line_counts: Some(true),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+        assert_eq!(stats.file_count, 3);
+        assert_eq!(stats.dir_count, 2);
+        assert_eq!(stats.total_size, 25);
+        assert_eq!(stats.total_lines, Some(6));
+
+        let largest: Vec<&str> = stats.largest_files.as_ref().unwrap().iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(largest, vec!["src/main.ts", "README.md"]);
+
+        let extensions = stats.extensions.unwrap();
+        assert_eq!(extensions[0].extension, "ts");
+        assert_eq!(extensions[0].file_count, 2);
+        assert_eq!(extensions[0].total_size, 15);
+
+        let minimal = compute_project_stats(
+            project_path.clone(),
+            Some(ProjectStatsOptions {
+                include_largest_files: Some(false),
+                include_extensions: Some(false),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+        assert!(minimal.largest_files.is_none());
+        assert!(minimal.extensions.is_none());
+        assert!(minimal.total_lines.is_none());
+
+        let capped = compute_project_stats(
+            project_path,
+            Some(ProjectStatsOptions {
+                include_line_counts: Some(true),
+                max_file_size_bytes: Some(10),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+        assert_eq!(capped.total_lines, Some(3));
+        assert_eq!(capped.warnings.len(), 1);
+        assert!(capped.warnings[0].contains("main.ts"));
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_render_tree_text() {
+        let project_dir = std::env::temp_dir().join("through_test_render_tree");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(project_dir.join("src/components")).unwrap();
+        std::fs::write(project_dir.join("src/components/Button.tsx"), "").unwrap();
+        std::fs::write(project_dir.join("src/index.ts"), "").unwrap();
+        std::fs::write(project_dir.join("package.json"), "{}").unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        let tree = render_tree_text(project_path.clone(), None, None).unwrap();
+        assert_eq!(
+            tree,
+            "through_test_render_tree\n\
+             ├── src\n\
+             │   ├── components\n\
+             │   │   └── Button.tsx\n\
+             │   └── index.ts\n\
+             └── package.json\n"
+        );
+
+        let shallow = render_tree_text(
+            project_path,
+            Some(1),
+            Some(RenderTreeOptions { markdown: Some(true) }),
+        )
+        .unwrap();
+        assert_eq!(shallow, "```\nthrough_test_render_tree\n├── src\n└── package.json\n```\n");
+
+        // Build output and gitignored files are left out
+        std::fs::create_dir_all(project_dir.join("node_modules/react")).unwrap();
+        std::fs::write(project_dir.join("node_modules/react/index.js"), "").unwrap();
+        std::fs::write(project_dir.join(".gitignore"), "*.log\n").unwrap();
+        std::fs::write(project_dir.join("debug.log"), "").unwrap();
+        let ignored = render_tree_text(project_dir.to_string_lossy().to_string(), Some(1), None).unwrap();
+        assert_eq!(ignored, "through_test_render_tree\n├── src\n├── .gitignore\n└── package.json\n");
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_permission_denied_paths_are_reported() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let project_dir = std::env::temp_dir().join("through_test_permission_denied");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        let locked = project_dir.join("locked");
+        std::fs::create_dir_all(&locked).unwrap();
+        std::fs::write(locked.join("secret.txt"), "").unwrap();
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o000)).unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        // Root ignores directory permissions, so there is nothing to observe
+        if std::fs::read_dir(&locked).is_err() {
+            let analysis = analyze_project_files(project_path.clone(), None).unwrap();
+            assert_eq!(analysis.permission_denied_paths, vec![to_forward_slashes(&locked)]);
+
+            let tree = list_project_files(project_path, None, None, None).unwrap();
+            assert_eq!(tree.permission_denied_paths, vec![to_forward_slashes(&locked.canonicalize().unwrap())]);
+            assert_eq!(tree.nodes[0].children.as_ref().map(Vec::len), Some(0));
+        }
+
+        // Cleanup
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_analyze_by_extension() {
+        let project_dir = std::env::temp_dir().join("through_test_by_extension");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(project_dir.join("node_modules/pkg")).unwrap();
+        std::fs::write(project_dir.join("a.ts"), "12345").unwrap();
+        std::fs::write(project_dir.join("b.TS"), "123").unwrap();
+        std::fs::write(project_dir.join("Makefile"), "1").unwrap();
+        std::fs::write(project_dir.join("node_modules/pkg/index.js"), "ignored").unwrap();
+
+        let analysis = analyze_project_files(project_dir.to_string_lossy().to_string(), None).unwrap();
+        assert_eq!(analysis.file_count, 3);
+        assert_eq!(analysis.total_size, 9);
+        assert_eq!(
+            analysis.by_extension,
+            vec![
+                ExtensionStats { extension: "ts".to_string(), file_count: 2, total_size: 8 },
+                ExtensionStats { extension: String::new(), file_count: 1, total_size: 1 },
+            ]
+        );
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_analyze_respects_gitignore() {
+        let project_dir = std::env::temp_dir().join("through_test_respect_gitignore");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(project_dir.join("generated")).unwrap();
+        std::fs::create_dir_all(project_dir.join("src")).unwrap();
+        std::fs::write(project_dir.join(".gitignore"), "generated/\n*.log\n").unwrap();
+        std::fs::write(project_dir.join("src/.gitignore"), "scratch.ts\n").unwrap();
+        std::fs::write(project_dir.join("src/index.ts"), "").unwrap();
+        std::fs::write(project_dir.join("src/scratch.ts"), "").unwrap();
+        std::fs::write(project_dir.join("generated/bundle.js"), "").unwrap();
+        std::fs::write(project_dir.join("debug.log"), "").unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        // .gitignore, src/.gitignore and src/index.ts
+        let analysis = analyze_project_files(project_path.clone(), None).unwrap();
+        assert_eq!(analysis.file_count, 3);
+
+        let all = analyze_project_files(
+            project_path,
+            Some(AnalyzeOptions {
+                respect_gitignore: Some(false),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+        assert_eq!(all.file_count, 6);
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_analyze_dependency_details() {
+        let project_dir = std::env::temp_dir().join("through_test_dependency_details");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(
+            project_dir.join("package.json"),
+            r#"{"dependencies":{"react":"^18.2.0"},"devDependencies":{"vite":"^5.0.0"}}"#,
+        )
+        .unwrap();
+        std::fs::write(project_dir.join("Cargo.toml"), "[dependencies]\nserde = \"1.0\"\n").unwrap();
+
+        let analysis = analyze_project_files(project_dir.to_string_lossy().to_string(), None).unwrap();
+        assert_eq!(analysis.dependencies, vec!["react", "vite", "serde"]);
+
+        let details: Vec<(&str, &str, bool)> = analysis
+            .dependency_details
+            .iter()
+            .map(|d| (d.name.as_str(), d.version_spec.as_str(), d.dev))
+            .collect();
+        assert_eq!(
+            details,
+            vec![("react", "^18.2.0", false), ("vite", "^5.0.0", true), ("serde", "1.0", false)]
+        );
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_detect_package_manager() {
+        let project_dir = std::env::temp_dir().join("through_test_package_manager");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(project_dir.join("package.json"), "{}").unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        let analysis = analyze_project_files(project_path.clone(), None).unwrap();
+        assert_eq!(analysis.package_manager, None);
+        assert_eq!(analysis.lockfile, None);
+
+        std::fs::write(project_dir.join("package-lock.json"), "{}").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        std::fs::write(project_dir.join("pnpm-lock.yaml"), "").unwrap();
+
+        let analysis = analyze_project_files(project_path, None).unwrap();
+        assert_eq!(analysis.package_manager.as_deref(), Some("pnpm"));
+        assert_eq!(analysis.lockfile.as_deref(), Some("pnpm-lock.yaml"));
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_detect_framework_from_fixtures() {
+        let fixtures = [
+            (r#"{"dependencies":{"next":"14.1.0","react":"^18.2.0"}}"#, Some("next")),
+            (r#"{"dependencies":{"react":"^18.2.0"},"devDependencies":{"vite":"^5.0.0"}}"#, Some("vite")),
+            (r#"{"dependencies":{"@angular/core":"^17.0.0","rxjs":"~7.8.0"}}"#, Some("angular")),
+            (r#"{"devDependencies":{"@sveltejs/kit":"^2.0.0","svelte":"^4.2.0","vite":"^5.0.0"}}"#, Some("sveltekit")),
+            (r#"{"dependencies":{"nuxt":"^3.9.0","vue":"^3.4.0"}}"#, Some("nuxt")),
+            (r#"{"dependencies":{"gatsby":"^5.13.0"}}"#, Some("gatsby")),
+            (r#"{"dependencies":{"lodash":"^4.17.21"}}"#, None),
+        ];
+
+        let project_dir = std::env::temp_dir().join("through_test_detect_framework");
+        for (package_json, expected) in fixtures {
+            let _ = std::fs::remove_dir_all(&project_dir);
+            std::fs::create_dir_all(&project_dir).unwrap();
+            std::fs::write(project_dir.join("package.json"), package_json).unwrap();
+
+            let analysis = analyze_project_files(project_dir.to_string_lossy().to_string(), None).unwrap();
+            assert_eq!(analysis.detected_framework.as_deref(), expected, "{}", package_json);
+            if let Some(framework) = analysis.detected_framework {
+                assert!(crate::port_scanner::get_default_port(framework).is_ok());
+            }
+        }
+
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(project_dir.join("Cargo.toml"), "[dependencies]\naxum = \"0.7\"\ntokio = \"1\"\n").unwrap();
+        let analysis = analyze_project_files(project_dir.to_string_lossy().to_string(), None).unwrap();
+        assert_eq!(analysis.detected_framework.as_deref(), Some("axum"));
+        assert_eq!(crate::port_scanner::get_default_port("axum".to_string()).unwrap(), 3000);
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
 }
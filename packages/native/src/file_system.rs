@@ -0,0 +1,454 @@
+use crate::file_analyzer::{oversized_file_warning, DEFAULT_MAX_FILE_SIZE_BYTES};
+use napi::bindgen_prelude::*;
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileStat {
+    pub path: String,
+    pub exists: bool,
+    pub is_dir: bool,
+    pub size_bytes: i64,
+    /// Last modification time in milliseconds since the Unix epoch
+    pub modified_ms: Option<i64>,
+    pub is_symlink: bool,
+}
+
+fn stat_path(path: String) -> FileStat {
+    let link_metadata = match fs::symlink_metadata(&path) {
+        Ok(metadata) => metadata,
+        Err(_) => {
+            return FileStat {
+                path,
+                exists: false,
+                is_dir: false,
+                size_bytes: 0,
+                modified_ms: None,
+                is_symlink: false,
+            }
+        }
+    };
+
+    let is_symlink = link_metadata.file_type().is_symlink();
+    // Report the target for symlinks, falling back to the link itself when it is broken
+    let metadata = fs::metadata(&path).unwrap_or(link_metadata);
+
+    let modified_ms = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as i64);
+
+    FileStat {
+        path,
+        exists: true,
+        is_dir: metadata.is_dir(),
+        size_bytes: metadata.len() as i64,
+        modified_ms,
+        is_symlink,
+    }
+}
+
+/// Get file information for many paths in one call
+///
+/// # Arguments
+/// * `paths` - Paths to stat
+///
+/// # Returns
+/// * `Result<Vec<FileStat>>` - One entry per path, in order; missing paths get `exists: false`
+#[napi]
+pub fn stat_paths(paths: Vec<String>) -> Result<Vec<FileStat>> {
+    Ok(paths.into_iter().map(stat_path).collect())
+}
+
+/// Render a path with forward slashes, without touching the filesystem
+///
+/// On Windows, separators become `/`, the verbatim prefix added by `canonicalize`
+/// is dropped (`\\?\C:\app` becomes `C:/app`, `\\?\UNC\server\share` becomes
+/// `//server/share`) and the drive letter is uppercased. On Unix the path is left
+/// as is, since `\` is a valid file name character there.
+pub(crate) fn to_forward_slashes(path: &Path) -> String {
+    let path = path.to_string_lossy();
+
+    if cfg!(windows) {
+        let path = if let Some(unc) = path.strip_prefix(r"\\?\UNC\") {
+            format!(r"\\{}", unc)
+        } else {
+            path.strip_prefix(r"\\?\").unwrap_or(&path).to_string()
+        };
+
+        let mut normalized = path.replace('\\', "/");
+        if normalized.len() >= 2 && normalized.as_bytes()[1] == b':' {
+            normalized[..1].make_ascii_uppercase();
+        }
+        normalized
+    } else {
+        path.to_string()
+    }
+}
+
+/// Resolve `.` and `..` components lexically, for paths that don't exist
+fn clean_components(path: &Path) -> PathBuf {
+    use std::path::Component;
+
+    let mut cleaned = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !cleaned.pop() {
+                    cleaned.push("..");
+                }
+            }
+            other => cleaned.push(other.as_os_str()),
+        }
+    }
+    cleaned
+}
+
+/// Normalize a path to one canonical, forward-slash string form
+///
+/// # Arguments
+/// * `path` - Path to normalize
+///
+/// # Returns
+/// * `Result<String>` - Canonical path (symlinks resolved) when it exists, otherwise
+///   the path with `.`/`..` resolved lexically; separators as described in `to_forward_slashes`
+///
+/// # Platform Handling
+/// * Windows: drive letters are uppercased (`C:/Users/...`) and UNC paths keep
+///   their double leading slash (`//server/share/...`)
+/// * Unix: paths already use `/`; backslashes are kept since they are valid in names
+#[napi]
+pub fn normalize_path(path: String) -> Result<String> {
+    if path.is_empty() {
+        return Err(Error::new(Status::InvalidArg, "Path must not be empty"));
+    }
+
+    let path = Path::new(&path);
+    let resolved = path.canonicalize().unwrap_or_else(|_| clean_components(path));
+
+    Ok(to_forward_slashes(&resolved))
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileText {
+    /// File contents decoded to UTF-8
+    pub text: String,
+    /// WHATWG name of the encoding used to decode (e.g. "UTF-8", "windows-1252", "Shift_JIS")
+    pub detected_encoding: String,
+    /// True when detection was uncertain and the file was decoded as lossy UTF-8, or when
+    /// invalid sequences were replaced with U+FFFD
+    pub lossy: bool,
+}
+
+/// Decode raw file bytes, honoring a BOM, then UTF-8, then a statistical guess
+fn decode_text(bytes: &[u8]) -> FileText {
+    if let Some((encoding, bom_length)) = encoding_rs::Encoding::for_bom(bytes) {
+        let (text, lossy) = encoding.decode_without_bom_handling(&bytes[bom_length..]);
+        return FileText {
+            text: text.into_owned(),
+            detected_encoding: encoding.name().to_string(),
+            lossy,
+        };
+    }
+
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return FileText {
+            text: text.to_string(),
+            detected_encoding: encoding_rs::UTF_8.name().to_string(),
+            lossy: false,
+        };
+    }
+
+    let mut detector = chardetng::EncodingDetector::new();
+    detector.feed(bytes, true);
+    let (encoding, confident) = detector.guess_assess(None, true);
+
+    if !confident {
+        return FileText {
+            text: String::from_utf8_lossy(bytes).into_owned(),
+            detected_encoding: encoding_rs::UTF_8.name().to_string(),
+            lossy: true,
+        };
+    }
+
+    let (text, lossy) = encoding.decode_without_bom_handling(bytes);
+    FileText {
+        text: text.into_owned(),
+        detected_encoding: encoding.name().to_string(),
+        lossy,
+    }
+}
+
+/// Read a text file in any common encoding, decoding it to UTF-8
+///
+/// # Arguments
+/// * `path` - File to read
+///
+/// # Returns
+/// * `Result<FileText>` - Decoded text and the encoding it was read as. Errors if the file
+///   doesn't exist or is larger than the analyzer's file size limit (10 MiB)
+#[napi]
+pub fn read_file_text(path: String) -> Result<FileText> {
+    let file_path = Path::new(&path);
+
+    let metadata = fs::metadata(file_path).map_err(|e| {
+        Error::new(
+            Status::InvalidArg,
+            format!("Failed to read {}: {}", path, e),
+        )
+    })?;
+
+    if metadata.len() > DEFAULT_MAX_FILE_SIZE_BYTES {
+        return Err(Error::new(
+            Status::InvalidArg,
+            oversized_file_warning(file_path, DEFAULT_MAX_FILE_SIZE_BYTES),
+        ));
+    }
+
+    let bytes = fs::read(file_path).map_err(|e| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Failed to read {}: {}", path, e),
+        )
+    })?;
+
+    Ok(decode_text(&bytes))
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopyProgress {
+    pub files_copied: u32,
+    pub current_path: String,
+}
+
+/// Resolve a path that may not exist yet by canonicalizing its parent
+fn absolute_path(path: &Path) -> Option<PathBuf> {
+    if let Ok(canonical) = path.canonicalize() {
+        return Some(canonical);
+    }
+
+    let parent = path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    Some(parent.canonicalize().ok()?.join(path.file_name()?))
+}
+
+/// Whether `dest` is `src` or lies inside it
+///
+/// Canonical paths are compared when `dest` can be resolved; otherwise (e.g. several
+/// missing levels deep) both paths are compared lexically from the working directory.
+fn is_inside(src: &Path, dest: &Path) -> bool {
+    if let (Ok(src_abs), Some(dest_abs)) = (src.canonicalize(), absolute_path(dest)) {
+        return dest_abs.starts_with(&src_abs);
+    }
+
+    let Ok(cwd) = std::env::current_dir() else {
+        return false;
+    };
+    clean_components(&cwd.join(dest)).starts_with(clean_components(&cwd.join(src)))
+}
+
+/// Copy a file or directory tree, calling `on_file` after each file is copied
+fn copy_recursive(src: &Path, dest: &Path, overwrite: bool, on_file: &dyn Fn(u32, &Path)) -> Result<u32> {
+    if !src.exists() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Source path does not exist: {}", src.display()),
+        ));
+    }
+
+    if dest.exists() && !overwrite {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Destination already exists: {}", dest.display()),
+        ));
+    }
+
+    let io_error = |path: &Path, e: std::io::Error| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Failed to copy {}: {}", path.display(), e),
+        )
+    };
+
+    if src.is_file() {
+        fs::copy(src, dest).map_err(|e| io_error(src, e))?;
+        on_file(1, dest);
+        return Ok(1);
+    }
+
+    if is_inside(src, dest) {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Cannot copy {} into itself", src.display()),
+        ));
+    }
+
+    let mut files_copied = 0;
+
+    for entry in WalkDir::new(src).follow_links(false) {
+        let entry = entry.map_err(|e| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to read {}: {}", src.display(), e),
+            )
+        })?;
+
+        let relative = entry.path().strip_prefix(src).unwrap_or(entry.path());
+        let target = dest.join(relative);
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target).map_err(|e| io_error(entry.path(), e))?;
+        } else if entry.path().is_file() {
+            // Regular files and symlinks to files are copied by content
+            fs::copy(entry.path(), &target).map_err(|e| io_error(entry.path(), e))?;
+            files_copied += 1;
+            on_file(files_copied, &target);
+        }
+    }
+
+    Ok(files_copied)
+}
+
+pub struct CopyTask {
+    src: String,
+    dest: String,
+    overwrite: bool,
+    on_progress: Option<ThreadsafeFunction<CopyProgress, ErrorStrategy::Fatal>>,
+}
+
+impl Task for CopyTask {
+    type Output = u32;
+    type JsValue = u32;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let on_progress = self.on_progress.clone();
+        copy_recursive(Path::new(&self.src), Path::new(&self.dest), self.overwrite, &|files_copied, path| {
+            if let Some(tsfn) = &on_progress {
+                let progress = CopyProgress {
+                    files_copied,
+                    current_path: path.to_string_lossy().to_string(),
+                };
+                tsfn.call(progress, ThreadsafeFunctionCallMode::NonBlocking);
+            }
+        })
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// Copy a file or directory recursively, off the main thread
+///
+/// # Arguments
+/// * `src` - File or directory to copy
+/// * `dest` - Destination path (the copy itself, not its parent)
+/// * `overwrite` - Overwrite existing files and merge into existing directories
+/// * `on_progress` - Optional callback invoked with a `CopyProgress` after each file
+///
+/// # Returns
+/// * `Promise<number>` - Number of files copied; rejects when copying a directory into itself
+#[napi(ts_args_type = "src: string, dest: string, overwrite: boolean, onProgress?: (progress: CopyProgress) => void")]
+pub fn copy_path(
+    src: String,
+    dest: String,
+    overwrite: bool,
+    on_progress: Option<JsFunction>,
+) -> Result<AsyncTask<CopyTask>> {
+    let on_progress = match on_progress {
+        Some(callback) => Some(callback.create_threadsafe_function(0, |ctx| Ok(vec![ctx.value]))?),
+        None => None,
+    };
+
+    Ok(AsyncTask::new(CopyTask {
+        src,
+        dest,
+        overwrite,
+        on_progress,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stat_paths_mixed() {
+        let temp_file = std::env::temp_dir().join("through_test_stat_paths.txt");
+        std::fs::write(&temp_file, "hello").unwrap();
+
+        let stats = stat_paths(vec![
+            temp_file.to_string_lossy().to_string(),
+            "/nonexistent/path/12345".to_string(),
+        ])
+        .unwrap();
+
+        assert!(stats[0].exists);
+        assert!(!stats[0].is_dir);
+        assert_eq!(stats[0].size_bytes, 5);
+        assert!(stats[0].modified_ms.is_some());
+        assert!(!stats[1].exists);
+
+        // Cleanup
+        let _ = std::fs::remove_file(temp_file);
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        let temp_dir = std::env::temp_dir().canonicalize().unwrap();
+        let dotted = temp_dir.join("..").join(temp_dir.file_name().unwrap());
+        assert_eq!(
+            normalize_path(dotted.to_string_lossy().to_string()).unwrap(),
+            to_forward_slashes(&temp_dir)
+        );
+
+        let missing = normalize_path("/nonexistent/./path/../12345".to_string()).unwrap();
+        assert_eq!(missing, to_forward_slashes(Path::new("/nonexistent/12345")));
+        assert!(normalize_path(String::new()).is_err());
+    }
+
+    #[test]
+    fn test_copy_recursive_tree_and_self_copy() {
+        let root = std::env::temp_dir().join("through_test_copy_path");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src/nested")).unwrap();
+        std::fs::write(root.join("src/a.txt"), "a").unwrap();
+        std::fs::write(root.join("src/nested/b.txt"), "b").unwrap();
+
+        let copied = copy_recursive(&root.join("src"), &root.join("copy"), false, &|_, _| {}).unwrap();
+        assert_eq!(copied, 2);
+        assert_eq!(std::fs::read_to_string(root.join("copy/nested/b.txt")).unwrap(), "b");
+
+        assert!(copy_recursive(&root.join("src"), &root.join("copy"), false, &|_, _| {}).is_err());
+        assert!(copy_recursive(&root.join("src"), &root.join("src/nested/inner"), true, &|_, _| {}).is_err());
+
+        // A destination whose parent doesn't exist yet can't be canonicalized
+        assert!(copy_recursive(&root.join("src"), &root.join("src/new/inner"), true, &|_, _| {}).is_err());
+        assert!(!root.join("src/new").exists());
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn test_decode_text_encodings() {
+        let utf8 = decode_text("caf\u{e9}".as_bytes());
+        assert_eq!((utf8.text.as_str(), utf8.detected_encoding.as_str(), utf8.lossy), ("caf\u{e9}", "UTF-8", false));
+
+        let utf16 = decode_text(&[0xFF, 0xFE, b'h', 0, b'i', 0]);
+        assert_eq!((utf16.text.as_str(), utf16.detected_encoding.as_str()), ("hi", "UTF-16LE"));
+
+        let latin = decode_text(b"Le caf\xe9 est tr\xe8s bon, n'est-ce pas? C'\xe9tait d\xe9j\xe0 l\xe0.");
+        assert_eq!(latin.detected_encoding, "windows-1252");
+        assert!(latin.text.contains("caf\u{e9}"));
+        assert!(!latin.lossy);
+    }
+}
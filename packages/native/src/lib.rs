@@ -1,9 +1,17 @@
 #![deny(clippy::all)]
 
+mod dependency_analyzer;
 mod file_analyzer;
+mod file_system;
 mod port_scanner;
 mod process_manager;
+mod project_config;
+mod system_utils;
 
+pub use dependency_analyzer::*;
 pub use file_analyzer::*;
+pub use file_system::*;
 pub use port_scanner::*;
 pub use process_manager::*;
+pub use project_config::*;
+pub use system_utils::*;
@@ -1,16 +1,151 @@
+use lazy_static::lazy_static;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
-use std::net::{TcpListener, TcpStream, SocketAddr};
-use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::net::{IpAddr, TcpListener, TcpStream, SocketAddr, ToSocketAddrs, UdpSocket};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+lazy_static! {
+    /// Listeners held open by `reserve_port`, keyed by reservation id
+    static ref RESERVED_PORTS: Mutex<HashMap<u32, TcpListener>> = Mutex::new(HashMap::new());
+
+    /// Recently resolved host names, so polling loops don't repeat DNS lookups
+    static ref RESOLVED_HOSTS: Mutex<HashMap<String, (Instant, Vec<IpAddr>)>> = Mutex::new(HashMap::new());
+}
+
+/// How long a cached host resolution stays valid
+const RESOLVE_CACHE_TTL: Duration = Duration::from_secs(5);
+
+static NEXT_RESERVATION_ID: AtomicU32 = AtomicU32::new(1);
+
+/// Number of times a bind is retried when it fails for a transient reason
+const BIND_RETRIES: u32 = 3;
+
+/// Transport protocol a port is checked for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Protocol {
+    Tcp,
+    Udp,
+}
+
+/// Parse a `protocol` argument of "tcp" (default) or "udp"
+fn parse_protocol(protocol: Option<&str>) -> Result<Protocol> {
+    match protocol.unwrap_or("tcp") {
+        "tcp" => Ok(Protocol::Tcp),
+        "udp" => Ok(Protocol::Udp),
+        other => Err(Error::new(
+            Status::InvalidArg,
+            format!("Invalid protocol: {} (expected \"tcp\" or \"udp\")", other),
+        )),
+    }
+}
+
+/// Try to bind a TCP listener or UDP socket on the address, retrying transient failures
+///
+/// Binding fails with `AddrInUse` when the port is taken. Other failures such as
+/// running out of file descriptors are transient, so they are retried with a short
+/// backoff instead of being reported as an unavailable port.
+fn can_bind(addr: &str, protocol: Protocol) -> bool {
+    for attempt in 0..=BIND_RETRIES {
+        let bound = match protocol {
+            Protocol::Tcp => TcpListener::bind(addr).map(drop),
+            Protocol::Udp => UdpSocket::bind(addr).map(drop),
+        };
+
+        match bound {
+            Ok(()) => return true,
+            Err(e) if is_transient_bind_error(&e) && attempt < BIND_RETRIES => {
+                thread::sleep(Duration::from_millis(5 << attempt));
+            }
+            Err(_) => return false,
+        }
+    }
+
+    false
+}
+
+/// Format a `host:port` bind address, bracketing IPv6 literals
+fn bind_addr(host: &str, port: u16) -> String {
+    if host.contains(':') && !host.starts_with('[') {
+        format!("[{}]:{}", host, port)
+    } else {
+        format!("{}:{}", host, port)
+    }
+}
+
+/// Wildcard addresses to bind for an `ip_version` of "v4" (default), "v6" or "both"
+///
+/// An explicit `host` takes precedence and is the only address bound.
+fn bind_hosts(host: Option<String>, ip_version: Option<String>) -> Result<Vec<String>> {
+    if let Some(host) = host {
+        return Ok(vec![host]);
+    }
+
+    let hosts = match ip_version.as_deref().unwrap_or("v4") {
+        "v4" => vec!["0.0.0.0"],
+        "v6" => vec!["::"],
+        "both" => vec!["0.0.0.0", "::"],
+        other => {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!("Invalid ip_version: {} (expected \"v4\", \"v6\" or \"both\")", other),
+            ))
+        }
+    };
+
+    Ok(hosts.into_iter().map(String::from).collect())
+}
+
+/// A port is free only if it can be bound on every one of the hosts
+fn can_bind_all(hosts: &[String], port: u16, protocol: Protocol) -> bool {
+    hosts.iter().all(|host| can_bind(&bind_addr(host, port), protocol))
+}
+
+/// Whether a bind failed from resource exhaustion (out of file descriptors or socket
+/// buffers) rather than because of the port itself, so it can clear up on its own
+fn is_transient_bind_error(error: &io::Error) -> bool {
+    if error.kind() == io::ErrorKind::Interrupted {
+        return true;
+    }
+
+    #[cfg(unix)]
+    {
+        use nix::errno::Errno;
+
+        matches!(
+            error.raw_os_error(),
+            Some(code) if code == Errno::EMFILE as i32 || code == Errno::ENFILE as i32 || code == Errno::ENOBUFS as i32
+        )
+    }
+
+    #[cfg(windows)]
+    {
+        // WSAEMFILE and WSAENOBUFS
+        matches!(error.raw_os_error(), Some(10024) | Some(10055))
+    }
+}
 
 /// Check if a port is available for binding
 ///
 /// # Arguments
 /// * `port` - Port number to check (1-65535)
+/// * `ip_version` - Address family to check: "v4" (default, `0.0.0.0`), "v6" (`::`) or
+///   "both", which requires the port to be bindable on both families
 ///
 /// # Returns
 /// * `Result<bool>` - true if port is available, false if in use
 ///
+/// # Dual-Stack Behavior
+/// On Linux and macOS an IPv6 wildcard socket is dual-stack by default, so a "v6" check
+/// also sees IPv4 listeners; on Windows it only sees IPv6. Use "both" to catch a server
+/// listening on either family. On hosts without IPv6, "v6" and "both" always report
+/// the port as unavailable.
+///
 /// # Example
 /// ```
 /// let available = is_port_available(3000)?;
@@ -19,7 +154,7 @@ use std::time::Duration;
 /// }
 /// ```
 #[napi]
-pub fn is_port_available(port: u16) -> Result<bool> {
+pub fn is_port_available(port: u16, ip_version: Option<String>) -> Result<bool> {
     if port == 0 {
         return Err(Error::new(
             Status::InvalidArg,
@@ -27,26 +162,32 @@ pub fn is_port_available(port: u16) -> Result<bool> {
         ));
     }
 
-    // Try to bind to the port on all interfaces (0.0.0.0)
-    let addr = format!("0.0.0.0:{}", port);
+    // Try to bind to the port on all interfaces of each requested family
+    let hosts = bind_hosts(None, ip_version)?;
+    Ok(can_bind_all(&hosts, port, Protocol::Tcp))
+}
 
-    match TcpListener::bind(&addr) {
-        Ok(_) => Ok(true),
-        Err(_) => {
-            // Port is in use or cannot be bound
-            // Double-check by trying to connect to it
-            let addr = format!("127.0.0.1:{}", port);
-            match addr.parse::<SocketAddr>() {
-                Ok(socket_addr) => {
-                    match TcpStream::connect_timeout(&socket_addr, Duration::from_millis(100)) {
-                        Ok(_) => Ok(false), // Successfully connected, port is in use
-                        Err(_) => Ok(false), // Connection failed, but binding also failed
-                    }
-                }
-                Err(_) => Ok(false),
-            }
-        }
+/// Check if a UDP port is available for binding
+///
+/// # Arguments
+/// * `port` - Port number to check (1-65535)
+/// * `ip_version` - Address family to check: "v4" (default), "v6" or "both"
+///   (see `is_port_available`)
+///
+/// # Returns
+/// * `Result<bool>` - true if a UDP socket can be bound to the port, false if in use.
+///   TCP and UDP ports are separate, so a port free on one may be taken on the other
+#[napi]
+pub fn is_udp_port_available(port: u16, ip_version: Option<String>) -> Result<bool> {
+    if port == 0 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "Port number must be between 1 and 65535",
+        ));
     }
+
+    let hosts = bind_hosts(None, ip_version)?;
+    Ok(can_bind_all(&hosts, port, Protocol::Udp))
 }
 
 /// Check if a server is listening on a port by attempting to connect
@@ -73,41 +214,332 @@ pub fn is_port_listening(port: u16) -> Result<bool> {
         ));
     }
 
-    // Try to connect to localhost on the specified port (IPv4 and IPv6)
-    let addrs = [
-        format!("127.0.0.1:{}", port),      // IPv4 loopback
-        format!("[::1]:{}", port),           // IPv6 loopback
-        format!("localhost:{}", port),       // DNS resolution fallback
+    // Try to connect to localhost on the specified port (IPv4 and IPv6 loopback),
+    // then any other address "localhost" resolves to
+    let mut addrs: Vec<IpAddr> = vec![
+        IpAddr::from([127, 0, 0, 1]),
+        IpAddr::from([0u16, 0, 0, 0, 0, 0, 0, 1]),
     ];
+    for ip in resolve_host_cached("localhost") {
+        if !addrs.contains(&ip) {
+            addrs.push(ip);
+        }
+    }
 
-    for addr_str in &addrs {
-        if let Ok(socket_addr) = addr_str.parse::<SocketAddr>() {
-            match TcpStream::connect_timeout(&socket_addr, Duration::from_millis(200)) {
-                Ok(_) => return Ok(true), // Successfully connected
-                Err(_) => continue, // Try next address
-            }
+    for ip in addrs {
+        let socket_addr = SocketAddr::new(ip, port);
+        match TcpStream::connect_timeout(&socket_addr, Duration::from_millis(200)) {
+            Ok(_) => return Ok(true), // Successfully connected
+            Err(_) => continue, // Try next address
         }
     }
 
     Ok(false) // No connection succeeded
 }
 
+/// Resolve a host name to its addresses, reusing results for `RESOLVE_CACHE_TTL`
+///
+/// Failed lookups resolve to no addresses and are cached too, so an unresolvable
+/// host doesn't slow down every poll.
+fn resolve_host_cached(host: &str) -> Vec<IpAddr> {
+    let mut cache = RESOLVED_HOSTS.lock().unwrap_or_else(|e| e.into_inner());
+
+    if let Some((resolved_at, ips)) = cache.get(host) {
+        if resolved_at.elapsed() < RESOLVE_CACHE_TTL {
+            return ips.clone();
+        }
+    }
+
+    let ips: Vec<IpAddr> = (host, 0)
+        .to_socket_addrs()
+        .map(|addrs| addrs.map(|addr| addr.ip()).collect())
+        .unwrap_or_default();
+    cache.insert(host.to_string(), (Instant::now(), ips.clone()));
+    ips
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortCheckLatency {
+    /// Time spent resolving the host name
+    pub resolve_ms: f64,
+    /// Time spent attempting to bind the port
+    pub bind_ms: f64,
+    pub bind_succeeded: bool,
+    /// Time spent attempting to connect to the port
+    pub connect_ms: f64,
+    pub connect_succeeded: bool,
+    pub total_ms: f64,
+}
+
+fn elapsed_ms(start: Instant) -> f64 {
+    start.elapsed().as_secs_f64() * 1000.0
+}
+
+/// Measure how long each step of a port check takes, for diagnosing slow scans
+///
+/// # Arguments
+/// * `port` - Port number to check (1-65535)
+/// * `host` - Host to resolve, bind and connect to (default: "localhost")
+///
+/// # Returns
+/// * `Result<PortCheckLatency>` - Milliseconds per step; the bind and connect attempts are
+///   made once each, without the retries used by the regular checks
+#[napi]
+pub fn measure_port_check_latency(port: u16, host: Option<String>) -> Result<PortCheckLatency> {
+    if port == 0 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "Port number must be between 1 and 65535",
+        ));
+    }
+
+    let host = host.unwrap_or_else(|| "localhost".to_string());
+    let total_start = Instant::now();
+
+    let resolve_start = Instant::now();
+    let addr = resolve_socket_addr(&host, port)?;
+    let resolve_ms = elapsed_ms(resolve_start);
+
+    let bind_start = Instant::now();
+    let bind_succeeded = TcpListener::bind(addr).is_ok();
+    let bind_ms = elapsed_ms(bind_start);
+
+    let connect_start = Instant::now();
+    let connect_succeeded = TcpStream::connect_timeout(&addr, Duration::from_millis(200)).is_ok();
+    let connect_ms = elapsed_ms(connect_start);
+
+    Ok(PortCheckLatency {
+        resolve_ms,
+        bind_ms,
+        bind_succeeded,
+        connect_ms,
+        connect_succeeded,
+        total_ms: elapsed_ms(total_start),
+    })
+}
+
+/// Timeout for connecting to and reading from a probed port
+const PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortProtocol {
+    /// "https", "http" or "unknown"
+    pub protocol: String,
+    pub tls: bool,
+}
+
+/// Resolve `host:port`, preferring the first address returned
+fn resolve_socket_addr(host: &str, port: u16) -> Result<SocketAddr> {
+    (host, port)
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addrs| addrs.next())
+        .ok_or_else(|| {
+            Error::new(
+                Status::InvalidArg,
+                format!("Could not resolve host: {}", host),
+            )
+        })
+}
+
+/// Connect, send a payload and return whatever the server answers within the timeout
+fn probe_exchange(addr: &SocketAddr, payload: &[u8]) -> Result<Vec<u8>> {
+    let mut stream = TcpStream::connect_timeout(addr, PROBE_TIMEOUT).map_err(|e| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Nothing is listening on {}: {}", addr, e),
+        )
+    })?;
+
+    let _ = stream.set_read_timeout(Some(PROBE_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(PROBE_TIMEOUT));
+    let _ = stream.write_all(payload);
+
+    let mut buffer = [0u8; 64];
+    let read = stream.read(&mut buffer).unwrap_or(0);
+    Ok(buffer[..read].to_vec())
+}
+
+/// Build a minimal TLS 1.2 ClientHello record
+///
+/// It only needs to be well-formed enough for a TLS server to answer with a
+/// handshake or alert record; the handshake is never completed.
+fn tls_client_hello() -> Vec<u8> {
+    let cipher_suites: [u8; 8] = [0x13, 0x01, 0xc0, 0x2f, 0xc0, 0x2b, 0x00, 0x9c];
+
+    let mut body = vec![0x03, 0x03]; // client_version: TLS 1.2
+    body.extend_from_slice(&[0x42; 32]); // random
+    body.push(0x00); // session id length
+    body.extend_from_slice(&(cipher_suites.len() as u16).to_be_bytes());
+    body.extend_from_slice(&cipher_suites);
+    body.extend_from_slice(&[0x01, 0x00]); // compression methods: null
+
+    let mut handshake = vec![0x01]; // ClientHello
+    handshake.extend_from_slice(&(body.len() as u32).to_be_bytes()[1..]);
+    handshake.extend_from_slice(&body);
+
+    let mut record = vec![0x16, 0x03, 0x01]; // handshake record, TLS 1.0 framing
+    record.extend_from_slice(&(handshake.len() as u16).to_be_bytes());
+    record.extend_from_slice(&handshake);
+    record
+}
+
+/// Detect whether a port speaks HTTPS, plain HTTP or something else
+///
+/// # Arguments
+/// * `port` - Port number to probe (1-65535)
+/// * `host` - Host to connect to (default: "127.0.0.1")
+///
+/// # Returns
+/// * `Result<PortProtocol>` - Detected protocol, or error if nothing is listening
+///
+/// # Detection
+/// A TLS ClientHello is sent first: a TLS handshake or alert record means HTTPS.
+/// Plain HTTP servers usually answer it with a `400` response, which is detected
+/// as HTTP. Otherwise a `HEAD /` request is sent on a fresh connection.
+#[napi]
+pub fn probe_port_protocol(port: u16, host: Option<String>) -> Result<PortProtocol> {
+    if port == 0 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "Port number must be between 1 and 65535",
+        ));
+    }
+
+    let host = host.unwrap_or_else(|| "127.0.0.1".to_string());
+    let addr = resolve_socket_addr(&host, port)?;
+
+    // Handshake (0x16) or alert (0x15) record with a TLS major version
+    let is_tls_record =
+        |response: &[u8]| response.len() >= 2 && matches!(response[0], 0x15 | 0x16) && response[1] == 0x03;
+    let is_http_response = |response: &[u8]| response.starts_with(b"HTTP/");
+
+    let response = probe_exchange(&addr, &tls_client_hello())?;
+    if is_tls_record(&response) {
+        return Ok(PortProtocol {
+            protocol: "https".to_string(),
+            tls: true,
+        });
+    }
+
+    let is_http = is_http_response(&response) || {
+        let request = format!("HEAD / HTTP/1.0\r\nHost: {}\r\n\r\n", host);
+        is_http_response(&probe_exchange(&addr, request.as_bytes())?)
+    };
+
+    Ok(PortProtocol {
+        protocol: if is_http { "http" } else { "unknown" }.to_string(),
+        tls: false,
+    })
+}
+
+/// Check whether a server accepts a WebSocket upgrade (e.g. an HMR socket)
+///
+/// # Arguments
+/// * `port` - Port number to probe (1-65535)
+/// * `path` - Request path for the upgrade (default: "/")
+/// * `host` - Host to connect to (default: "127.0.0.1")
+///
+/// # Returns
+/// * `Result<bool>` - true if the server answered `101 Switching Protocols`
+#[napi]
+pub fn probe_websocket(port: u16, path: Option<String>, host: Option<String>) -> Result<bool> {
+    if port == 0 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "Port number must be between 1 and 65535",
+        ));
+    }
+
+    let path = path.unwrap_or_else(|| "/".to_string());
+    let host = host.unwrap_or_else(|| "127.0.0.1".to_string());
+    let addr = resolve_socket_addr(&host, port)?;
+
+    let request = format!(
+        "GET {} HTTP/1.1\r\n\
+         Host: {}\r\n\
+         Upgrade: websocket\r\n\
+         Connection: Upgrade\r\n\
+         Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+         Sec-WebSocket-Version: 13\r\n\r\n",
+        path,
+        // IPv6 literals need brackets in the Host header too
+        bind_addr(&host, port)
+    );
+
+    let response = probe_exchange(&addr, request.as_bytes())?;
+    let status_line = String::from_utf8_lossy(&response);
+
+    Ok(status_line.starts_with("HTTP/1.1 101") || status_line.starts_with("HTTP/1.0 101"))
+}
+
+/// Check if a server is accepting connections on a Unix domain socket
+///
+/// # Arguments
+/// * `socket_path` - Filesystem path of the Unix socket
+///
+/// # Returns
+/// * `Result<bool>` - true if a connection succeeded, false otherwise
+///
+/// # Platform Handling
+/// * Unix/Linux/macOS: Attempts a `UnixStream` connection
+/// * Windows: Returns a not-supported error
+#[napi]
+pub fn is_unix_socket_listening(socket_path: String) -> Result<bool> {
+    if socket_path.is_empty() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "Socket path must not be empty",
+        ));
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::net::UnixStream;
+
+        match UnixStream::connect(&socket_path) {
+            Ok(_) => Ok(true),   // Something accepted the connection
+            Err(_) => Ok(false), // Missing, stale or not a socket
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        Err(Error::new(
+            Status::GenericFailure,
+            "Unix domain sockets are not supported on this platform",
+        ))
+    }
+}
+
 /// Find an available port within a specified range
 ///
 /// # Arguments
 /// * `start_port` - Starting port number (inclusive)
 /// * `end_port` - Ending port number (inclusive)
+/// * `host` - Interface to bind on (default: "0.0.0.0"); use "127.0.0.1" for loopback-only frameworks
+/// * `ip_version` - Address family when no `host` is given: "v4" (default), "v6" or "both"
+///   (see `is_port_available`)
+/// * `protocol` - "tcp" (default) or "udp"
 ///
 /// # Returns
 /// * `Result<u16>` - First available port found, or error if none available
 ///
 /// # Example
 /// ```
-/// let port = find_available_port(3000, 3100)?;
+/// let port = find_available_port(3000, 3100, None, None, None)?;
 /// println!("Found available port: {}", port);
 /// ```
 #[napi]
-pub fn find_available_port(start_port: u16, end_port: u16) -> Result<u16> {
+pub fn find_available_port(
+    start_port: u16,
+    end_port: u16,
+    host: Option<String>,
+    ip_version: Option<String>,
+    protocol: Option<String>,
+) -> Result<u16> {
     if start_port == 0 || end_port == 0 {
         return Err(Error::new(
             Status::InvalidArg,
@@ -125,13 +557,12 @@ pub fn find_available_port(start_port: u16, end_port: u16) -> Result<u16> {
         ));
     }
 
+    let hosts = bind_hosts(host, ip_version)?;
+    let protocol = parse_protocol(protocol.as_deref())?;
+
     // Iterate through the port range
     for port in start_port..=end_port {
-        let addr = format!("0.0.0.0:{}", port);
-
-        if let Ok(listener) = TcpListener::bind(&addr) {
-            // Port is available, drop the listener and return the port
-            drop(listener);
+        if can_bind_all(&hosts, port, protocol) {
             return Ok(port);
         }
     }
@@ -151,17 +582,32 @@ pub fn find_available_port(start_port: u16, end_port: u16) -> Result<u16> {
 /// * `start_port` - Starting port number (inclusive)
 /// * `end_port` - Ending port number (inclusive)
 /// * `count` - Number of ports to find
+/// * `host` - Interface to bind on (default: "0.0.0.0"); use "127.0.0.1" for loopback-only frameworks
+/// * `ip_version` - Address family when no `host` is given: "v4" (default), "v6" or "both"
+///   (see `is_port_available`)
 ///
 /// # Returns
 /// * `Result<Vec<u16>>` - Vector of available ports, or error if not enough available
 ///
+/// # Large Ranges
+/// Ports are probed one at a time and each listener is closed before the next bind,
+/// so at most one socket is open at once. Ranges spanning tens of thousands of ports
+/// are safe to scan: binds that fail for transient reasons (e.g. file descriptor or
+/// ephemeral limits) are retried with a short backoff rather than reported as in use.
+///
 /// # Example
 /// ```
-/// let ports = find_available_ports(3000, 3100, 3)?;
+/// let ports = find_available_ports(3000, 3100, 3, None, None)?;
 /// println!("Found ports: {:?}", ports);
 /// ```
 #[napi]
-pub fn find_available_ports(start_port: u16, end_port: u16, count: u32) -> Result<Vec<u16>> {
+pub fn find_available_ports(
+    start_port: u16,
+    end_port: u16,
+    count: u32,
+    host: Option<String>,
+    ip_version: Option<String>,
+) -> Result<Vec<u16>> {
     if start_port == 0 || end_port == 0 {
         return Err(Error::new(
             Status::InvalidArg,
@@ -194,6 +640,7 @@ pub fn find_available_ports(start_port: u16, end_port: u16, count: u32) -> Resul
         ));
     }
 
+    let hosts = bind_hosts(host, ip_version)?;
     let mut available_ports = Vec::new();
 
     for port in start_port..=end_port {
@@ -201,10 +648,7 @@ pub fn find_available_ports(start_port: u16, end_port: u16, count: u32) -> Resul
             break;
         }
 
-        let addr = format!("0.0.0.0:{}", port);
-
-        if let Ok(listener) = TcpListener::bind(&addr) {
-            drop(listener);
+        if can_bind_all(&hosts, port, Protocol::Tcp) {
             available_ports.push(port);
         }
     }
@@ -223,6 +667,215 @@ pub fn find_available_ports(start_port: u16, end_port: u16, count: u32) -> Resul
     Ok(available_ports)
 }
 
+/// PIDs of the processes listening on a TCP port
+///
+/// # Platform Handling
+/// * Unix/Linux/macOS: `lsof -nP -iTCP:PORT -sTCP:LISTEN -t`
+/// * Windows: `netstat -ano`, matching LISTENING rows for the port
+pub(crate) fn port_owner_pids(port: u16) -> Result<Vec<u32>> {
+    #[cfg(unix)]
+    {
+        let output = std::process::Command::new("lsof")
+            .args(["-nP", &format!("-iTCP:{}", port), "-sTCP:LISTEN", "-t"])
+            .output()
+            .map_err(|e| {
+                Error::new(
+                    Status::GenericFailure,
+                    format!("Failed to execute lsof: {}", e),
+                )
+            })?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut pids: Vec<u32> = Vec::new();
+        for pid in stdout.lines().filter_map(|line| line.trim().parse().ok()) {
+            if !pids.contains(&pid) {
+                pids.push(pid);
+            }
+        }
+        Ok(pids)
+    }
+
+    #[cfg(windows)]
+    {
+        let output = std::process::Command::new("netstat")
+            .args(["-ano", "-p", "TCP"])
+            .output()
+            .map_err(|e| {
+                Error::new(
+                    Status::GenericFailure,
+                    format!("Failed to execute netstat: {}", e),
+                )
+            })?;
+
+        // Rows look like: TCP    0.0.0.0:3000    0.0.0.0:0    LISTENING    1234
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let suffix = format!(":{}", port);
+        let mut pids: Vec<u32> = Vec::new();
+        for line in stdout.lines() {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            if columns.len() == 5 && columns[1].ends_with(&suffix) && columns[3] == "LISTENING" {
+                if let Ok(pid) = columns[4].parse() {
+                    if !pids.contains(&pid) {
+                        pids.push(pid);
+                    }
+                }
+            }
+        }
+        Ok(pids)
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        Err(Error::new(
+            Status::GenericFailure,
+            "Port owner lookup not supported on this platform",
+        ))
+    }
+}
+
+/// Get the PID of the process listening on a TCP port
+///
+/// # Arguments
+/// * `port` - Port number to look up
+///
+/// # Returns
+/// * `Result<Option<u32>>` - PID of the first listener, or None if nothing listens on the port.
+///   When several processes share the port (e.g. forked workers), the first one reported wins
+///
+/// # Platform Handling
+/// * Unix/Linux/macOS: `lsof -nP -iTCP:PORT -sTCP:LISTEN -t`
+/// * Windows: `netstat -ano`, matching LISTENING rows for the port
+#[napi]
+pub fn get_port_owner_pid(port: u16) -> Result<Option<u32>> {
+    if port == 0 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "Port number must be between 1 and 65535",
+        ));
+    }
+
+    Ok(port_owner_pids(port)?.first().copied())
+}
+
+/// Interval between checks in `detect_server_port`
+const DETECT_PORT_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Find which of the candidate ports a spawned server actually listens on
+///
+/// # Arguments
+/// * `pid` - PID returned by `spawn_dev_server` (child processes count too)
+/// * `candidate_ports` - Ports the framework might pick (e.g. 3000, 3001, 3002)
+/// * `timeout_ms` - How long to wait for the server to start listening
+///
+/// # Returns
+/// * `Result<u16>` - First candidate port owned by the process or one of its children
+#[napi]
+pub fn detect_server_port(pid: u32, candidate_ports: Vec<u16>, timeout_ms: u32) -> Result<u16> {
+    crate::process_manager::validate_pid(pid)?;
+
+    if candidate_ports.is_empty() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "At least one candidate port is required",
+        ));
+    }
+
+    let deadline = std::time::Instant::now() + Duration::from_millis(timeout_ms as u64);
+
+    loop {
+        let listening: Vec<u16> = candidate_ports
+            .iter()
+            .copied()
+            .filter(|port| *port != 0 && is_port_listening(*port).unwrap_or(false))
+            .collect();
+
+        if !listening.is_empty() {
+            // Dev servers are often grandchildren of the spawned PID (npm -> node)
+            let tree = crate::process_manager::process_tree(pid);
+            for port in listening {
+                if port_owner_pids(port)?.iter().any(|owner| tree.contains(owner)) {
+                    return Ok(port);
+                }
+            }
+        }
+
+        if !crate::process_manager::is_process_running(pid)? {
+            return Err(Error::new(
+                Status::GenericFailure,
+                format!("Process {} exited before listening on a candidate port", pid),
+            ));
+        }
+
+        if std::time::Instant::now() >= deadline {
+            return Err(Error::new(
+                Status::GenericFailure,
+                format!(
+                    "Process {} did not listen on any of {:?} within {}ms",
+                    pid, candidate_ports, timeout_ms
+                ),
+            ));
+        }
+
+        thread::sleep(DETECT_PORT_INTERVAL);
+    }
+}
+
+/// Reserve a port by binding a listener and holding it open
+///
+/// # Arguments
+/// * `port` - Port number to reserve (1-65535)
+///
+/// # Returns
+/// * `Result<u32>` - Reservation id to pass to `release_port`
+///
+/// # Usage
+/// Reserve the port as soon as it is picked, then release it right before spawning
+/// the framework. The listener is bound with `SO_REUSEADDR` (the default for
+/// `TcpListener` on Unix), so the framework can rebind immediately after release
+/// instead of racing other processes between the check and the spawn.
+#[napi]
+pub fn reserve_port(port: u16) -> Result<u32> {
+    if port == 0 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "Port number must be between 1 and 65535",
+        ));
+    }
+
+    let addr = format!("0.0.0.0:{}", port);
+    let listener = TcpListener::bind(&addr).map_err(|e| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Failed to reserve port {}: {}", port, e),
+        )
+    })?;
+
+    let id = NEXT_RESERVATION_ID.fetch_add(1, Ordering::SeqCst);
+    RESERVED_PORTS
+        .lock()
+        .map_err(|_| Error::new(Status::GenericFailure, "Port reservation lock poisoned"))?
+        .insert(id, listener);
+
+    Ok(id)
+}
+
+/// Release a port previously reserved with `reserve_port`
+///
+/// # Arguments
+/// * `id` - Reservation id returned by `reserve_port`
+///
+/// # Returns
+/// * `Result<bool>` - true if the reservation existed and was released
+#[napi]
+pub fn release_port(id: u32) -> Result<bool> {
+    let listener = RESERVED_PORTS
+        .lock()
+        .map_err(|_| Error::new(Status::GenericFailure, "Port reservation lock poisoned"))?
+        .remove(&id);
+
+    Ok(listener.is_some())
+}
+
 /// Get the default port for common frameworks
 ///
 /// # Arguments
@@ -248,6 +901,9 @@ pub fn get_default_port(framework: String) -> Result<u16> {
         "gatsby" => 8000,
         "remix" => 3000,
         "astro" => 3000,
+        "actix" | "actix-web" => 8080,
+        "axum" => 3000,
+        "rocket" => 8000,
         _ => {
             return Err(Error::new(
                 Status::InvalidArg,
@@ -263,49 +919,247 @@ pub fn get_default_port(framework: String) -> Result<u16> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_port_available_ip_versions() {
+        let listener = TcpListener::bind("0.0.0.0:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        assert!(!is_port_available(port, Some("v4".to_string())).unwrap());
+        assert!(!is_port_available(port, Some("both".to_string())).unwrap());
+        assert!(is_port_available(port, Some("v5".to_string())).is_err());
+    }
+
     #[test]
     fn test_is_port_available_zero() {
-        let result = is_port_available(0);
+        let result = is_port_available(0, None);
         assert!(result.is_err());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_is_unix_socket_listening() {
+        let socket_path = std::env::temp_dir().join("through_test_unix_socket.sock");
+        let _ = std::fs::remove_file(&socket_path);
+        let path_str = socket_path.to_string_lossy().to_string();
+
+        assert!(!is_unix_socket_listening(path_str.clone()).unwrap());
+
+        let listener = std::os::unix::net::UnixListener::bind(&socket_path).unwrap();
+        assert!(is_unix_socket_listening(path_str).unwrap());
+
+        // Cleanup
+        drop(listener);
+        let _ = std::fs::remove_file(socket_path);
+    }
+
+    #[test]
+    fn test_probe_port_protocol_http_and_unknown() {
+        let http_listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let http_port = http_listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            for mut stream in http_listener.incoming().flatten() {
+                let mut buffer = [0u8; 512];
+                let _ = stream.read(&mut buffer);
+                let _ = stream.write_all(b"HTTP/1.1 400 Bad Request\r\n\r\n");
+            }
+        });
+
+        let silent_listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let silent_port = silent_listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            for stream in silent_listener.incoming().flatten() {
+                drop(stream);
+            }
+        });
+
+        let http = probe_port_protocol(http_port, None).unwrap();
+        assert_eq!(http.protocol, "http");
+        assert!(!http.tls);
+
+        let unknown = probe_port_protocol(silent_port, None).unwrap();
+        assert_eq!(unknown.protocol, "unknown");
+    }
+
+    #[test]
+    fn test_probe_port_protocol_tls_alert() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buffer = [0u8; 512];
+                let _ = stream.read(&mut buffer);
+                // Fatal handshake_failure alert, as a TLS server sends for a plaintext request
+                let _ = stream.write_all(&[0x15, 0x03, 0x01, 0x00, 0x02, 0x02, 0x28]);
+            }
+        });
+
+        let tls = probe_port_protocol(port, None).unwrap();
+        assert_eq!(tls.protocol, "https");
+        assert!(tls.tls);
+    }
+
+    #[test]
+    fn test_probe_websocket() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buffer = [0u8; 512];
+                let read = stream.read(&mut buffer).unwrap_or(0);
+                let request = String::from_utf8_lossy(&buffer[..read]).to_string();
+                let response = if request.starts_with("GET /hmr ") && request.contains("Upgrade: websocket") {
+                    "HTTP/1.1 101 Switching Protocols\r\n\r\n"
+                } else {
+                    "HTTP/1.1 404 Not Found\r\n\r\n"
+                };
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        assert!(probe_websocket(port, Some("/hmr".to_string()), None).unwrap());
+        assert!(!probe_websocket(port, None, None).unwrap());
+    }
+
+    #[test]
+    fn test_probe_websocket_brackets_ipv6_host() {
+        // Skip where the loopback interface has no IPv6 address
+        let Ok(listener) = TcpListener::bind("[::1]:0") else {
+            return;
+        };
+        let port = listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buffer = [0u8; 512];
+                let read = stream.read(&mut buffer).unwrap_or(0);
+                let request = String::from_utf8_lossy(&buffer[..read]).to_string();
+                let response = if request.contains(&format!("Host: [::1]:{}\r\n", port)) {
+                    "HTTP/1.1 101 Switching Protocols\r\n\r\n"
+                } else {
+                    "HTTP/1.1 400 Bad Request\r\n\r\n"
+                };
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        assert!(probe_websocket(port, None, Some("::1".to_string())).unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_detect_server_port_own_listener() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let other = find_available_port(52000, 52100, None, None, None).unwrap();
+
+        let result = detect_server_port(std::process::id(), vec![other, port], 2000);
+        assert_eq!(result.unwrap(), port);
+    }
+
+    #[test]
+    fn test_detect_server_port_rejects_invalid_pid() {
+        assert!(detect_server_port(0, vec![3000], 100).is_err());
+        assert!(detect_server_port(u32::MAX, vec![3000], 100).is_err());
+    }
+
+    #[test]
+    fn test_resolve_host_cached_reuses_result() {
+        let first = resolve_host_cached("localhost");
+        assert!(first.iter().any(|ip| ip.is_loopback()));
+        assert!(RESOLVED_HOSTS.lock().unwrap().contains_key("localhost"));
+        assert_eq!(resolve_host_cached("localhost"), first);
+    }
+
+    #[test]
+    fn test_measure_port_check_latency_listening_port() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let latency = measure_port_check_latency(port, Some("127.0.0.1".to_string())).unwrap();
+        assert!(!latency.bind_succeeded);
+        assert!(latency.connect_succeeded);
+        assert!(latency.total_ms >= latency.resolve_ms + latency.bind_ms + latency.connect_ms);
+    }
+
     #[test]
     fn test_find_available_port_valid_range() {
         // Find a port in a very high range that's likely available
-        let result = find_available_port(50000, 50100);
+        let result = find_available_port(50000, 50100, None, None, None);
         assert!(result.is_ok());
         if let Ok(port) = result {
-            assert!(port >= 50000 && port <= 50100);
+            assert!((50000..=50100).contains(&port));
         }
     }
 
+    #[test]
+    fn test_find_available_port_loopback_host() {
+        // A loopback-only listener must make the port unavailable for a loopback bind
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let taken = listener.local_addr().unwrap().port();
+
+        let result = find_available_port(taken, taken, Some("127.0.0.1".to_string()), None, None);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_find_available_port_invalid_range() {
-        let result = find_available_port(5000, 4000);
+        let result = find_available_port(5000, 4000, None, None, None);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_find_available_port_zero() {
-        let result = find_available_port(0, 100);
+        let result = find_available_port(0, 100, None, None, None);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_find_available_ports_valid() {
-        let result = find_available_ports(50000, 50100, 3);
+        let result = find_available_ports(50000, 50100, 3, None, None);
         assert!(result.is_ok());
         if let Ok(ports) = result {
             assert_eq!(ports.len(), 3);
         }
     }
 
+    #[test]
+    fn test_find_available_ports_wide_range() {
+        // Regression test: scanning a 10k-wide range must not yield spurious failures.
+        // Stays below the Linux ephemeral range so concurrent connections don't take ports.
+        let result = find_available_ports(20000, 29999, 5000, None, None);
+        assert!(result.is_ok());
+        if let Ok(ports) = result {
+            assert_eq!(ports.len(), 5000);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_transient_bind_errors() {
+        use nix::errno::Errno;
+
+        assert!(is_transient_bind_error(&io::Error::from_raw_os_error(Errno::EMFILE as i32)));
+        assert!(is_transient_bind_error(&io::Error::from_raw_os_error(Errno::ENOBUFS as i32)));
+        assert!(!is_transient_bind_error(&io::Error::from_raw_os_error(Errno::EADDRINUSE as i32)));
+        assert!(!is_transient_bind_error(&io::Error::from_raw_os_error(Errno::EACCES as i32)));
+    }
+
     #[test]
     fn test_find_available_ports_too_many() {
-        let result = find_available_ports(50000, 50005, 10);
+        let result = find_available_ports(50000, 50005, 10, None, None);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_reserve_and_release_port() {
+        let port = find_available_port(51000, 51100, None, None, None).unwrap();
+        let id = reserve_port(port).unwrap();
+        assert!(!is_port_available(port, None).unwrap());
+
+        assert!(release_port(id).unwrap());
+        assert!(is_port_available(port, None).unwrap());
+        assert!(!release_port(id).unwrap());
+    }
+
     #[test]
     fn test_get_default_port_react() {
         let result = get_default_port("react".to_string());
@@ -325,4 +1179,30 @@ mod tests {
         let result = get_default_port("unknownframework".to_string());
         assert!(result.is_err());
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_port_owner_pid() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        assert_eq!(get_port_owner_pid(port).unwrap(), Some(std::process::id()));
+        drop(listener);
+        assert_eq!(get_port_owner_pid(port).unwrap(), None);
+        assert!(get_port_owner_pid(0).is_err());
+    }
+
+    #[test]
+    fn test_udp_port_checks_are_separate_from_tcp() {
+        let socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+        let port = socket.local_addr().unwrap().port();
+
+        assert!(!is_udp_port_available(port, None).unwrap());
+        assert!(is_port_available(port, None).unwrap());
+
+        let found = find_available_port(port, port.saturating_add(20), None, None, Some("udp".to_string())).unwrap();
+        assert_ne!(found, port);
+        assert_eq!(find_available_port(port, port, None, None, None).unwrap(), port);
+        assert!(find_available_port(port, port, None, None, Some("sctp".to_string())).is_err());
+    }
 }
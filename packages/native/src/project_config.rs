@@ -0,0 +1,249 @@
+use crate::file_analyzer::{oversized_file_warning, read_to_string_capped, DEFAULT_MAX_FILE_SIZE_BYTES};
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// File name of the per-project config, relative to the project root
+const PROJECT_CONFIG_FILE: &str = ".through.json";
+
+/// Project settings loaded from `.through.json`
+///
+/// # Schema
+/// ```json
+/// {
+///   "command": "npm",              // optional string: run command
+///   "args": ["run", "dev"],        // optional array of strings
+///   "port": 3000,                  // optional integer, 1-65535
+///   "env": { "NODE_ENV": "dev" }   // optional object of string values
+/// }
+/// ```
+/// Any other top-level field is rejected so typos don't go unnoticed.
+#[napi(object)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProjectConfig {
+    pub command: Option<String>,
+    pub args: Vec<String>,
+    pub port: Option<u32>,
+    pub env: HashMap<String, String>,
+}
+
+/// A single schema violation, keyed by the offending field path (e.g. `env.PORT`)
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigIssue {
+    /// Dotted path of the offending field, or `$` for the document itself
+    pub field: String,
+    pub message: String,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectConfigResult {
+    /// The validated config; None when `errors` is not empty
+    pub config: Option<ProjectConfig>,
+    /// Every schema violation found, empty for a valid or missing file
+    pub errors: Vec<ConfigIssue>,
+}
+
+impl ConfigIssue {
+    fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Validate a parsed `.through.json` document, collecting every issue instead of stopping at the first
+fn parse_project_config(value: &Value) -> std::result::Result<ProjectConfig, Vec<ConfigIssue>> {
+    let object = match value.as_object() {
+        Some(object) => object,
+        None => return Err(vec![ConfigIssue::new("$", "expected an object")]),
+    };
+
+    let mut config = ProjectConfig::default();
+    let mut issues = Vec::new();
+
+    for (key, value) in object {
+        match key.as_str() {
+            "command" => match value.as_str() {
+                Some(command) if !command.trim().is_empty() => config.command = Some(command.to_string()),
+                Some(_) => issues.push(ConfigIssue::new("command", "must not be empty")),
+                None => issues.push(ConfigIssue::new("command", "expected a string")),
+            },
+            "args" => match value.as_array() {
+                Some(args) => {
+                    for (index, arg) in args.iter().enumerate() {
+                        match arg.as_str() {
+                            Some(arg) => config.args.push(arg.to_string()),
+                            None => issues.push(ConfigIssue::new(format!("args[{}]", index), "expected a string")),
+                        }
+                    }
+                }
+                None => issues.push(ConfigIssue::new("args", "expected an array of strings")),
+            },
+            "port" => match value.as_u64() {
+                Some(port) if (1..=65535).contains(&port) => config.port = Some(port as u32),
+                _ => issues.push(ConfigIssue::new("port", "expected an integer between 1 and 65535")),
+            },
+            "env" => match value.as_object() {
+                Some(env) => {
+                    for (name, value) in env {
+                        match value.as_str() {
+                            Some(value) => {
+                                config.env.insert(name.clone(), value.to_string());
+                            }
+                            None => issues.push(ConfigIssue::new(format!("env.{}", name), "expected a string")),
+                        }
+                    }
+                }
+                None => issues.push(ConfigIssue::new("env", "expected an object of strings")),
+            },
+            _ => issues.push(ConfigIssue::new(key.clone(), "unknown field")),
+        }
+    }
+
+    if issues.is_empty() {
+        Ok(config)
+    } else {
+        Err(issues)
+    }
+}
+
+/// Load and validate the project's `.through.json`
+///
+/// # Arguments
+/// * `project_path` - Root path of the project
+///
+/// # Returns
+/// * `Result<ProjectConfigResult>` - Parsed config, or the default config when the file is
+///   absent. Invalid configs (including malformed JSON, reported on `$`) come back with no
+///   config and one `ConfigIssue` per offending field. Errors only if the file can't be read
+#[napi]
+pub fn load_project_config(project_path: String) -> Result<ProjectConfigResult> {
+    let path = Path::new(&project_path);
+
+    if !path.is_dir() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Project path is not a directory: {}", project_path),
+        ));
+    }
+
+    let config_path = path.join(PROJECT_CONFIG_FILE);
+    if !config_path.exists() {
+        return Ok(ProjectConfigResult {
+            config: Some(ProjectConfig::default()),
+            errors: Vec::new(),
+        });
+    }
+
+    let content = match read_to_string_capped(&config_path, DEFAULT_MAX_FILE_SIZE_BYTES) {
+        Ok(Some(content)) => content,
+        Ok(None) => {
+            return Err(Error::new(
+                Status::InvalidArg,
+                oversized_file_warning(&config_path, DEFAULT_MAX_FILE_SIZE_BYTES),
+            ))
+        }
+        Err(e) => {
+            return Err(Error::new(
+                Status::GenericFailure,
+                format!("Failed to read {}: {}", config_path.display(), e),
+            ))
+        }
+    };
+
+    let parsed = serde_json::from_str::<Value>(&content)
+        .map_err(|e| vec![ConfigIssue::new("$", format!("invalid JSON: {}", e))])
+        .and_then(|value| parse_project_config(&value));
+
+    Ok(match parsed {
+        Ok(config) => ProjectConfigResult {
+            config: Some(config),
+            errors: Vec::new(),
+        },
+        Err(errors) => ProjectConfigResult { config: None, errors },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_project_config_missing_file_returns_default() {
+        let project_dir = std::env::temp_dir().join("through_test_config_missing");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+
+        let result = load_project_config(project_dir.to_string_lossy().to_string()).unwrap();
+        assert_eq!(result.config, Some(ProjectConfig::default()));
+        assert!(result.errors.is_empty());
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_load_project_config_valid() {
+        let project_dir = std::env::temp_dir().join("through_test_config_valid");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(
+            project_dir.join(PROJECT_CONFIG_FILE),
+            r#"{"command":"npm","args":["run","dev"],"port":5173,"env":{"NODE_ENV":"development"}}"#,
+        )
+        .unwrap();
+
+        let config = load_project_config(project_dir.to_string_lossy().to_string()).unwrap().config.unwrap();
+        assert_eq!(config.command.as_deref(), Some("npm"));
+        assert_eq!(config.args, vec!["run", "dev"]);
+        assert_eq!(config.port, Some(5173));
+        assert_eq!(config.env.get("NODE_ENV").map(String::as_str), Some("development"));
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+
+    #[test]
+    fn test_parse_project_config_lists_every_invalid_field() {
+        let value = serde_json::json!({
+            "command": 42,
+            "port": 70000,
+            "env": { "DEBUG": true },
+            "prot": 3000
+        });
+
+        let issues = parse_project_config(&value).unwrap_err();
+        let mut fields: Vec<&str> = issues.iter().map(|issue| issue.field.as_str()).collect();
+        fields.sort();
+        assert_eq!(fields, vec!["command", "env.DEBUG", "port", "prot"]);
+    }
+
+    #[test]
+    fn test_load_project_config_returns_structured_errors() {
+        let project_dir = std::env::temp_dir().join("through_test_config_invalid");
+        let _ = std::fs::remove_dir_all(&project_dir);
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        std::fs::write(project_dir.join(PROJECT_CONFIG_FILE), r#"{"port":0,"args":"dev"}"#).unwrap();
+        let result = load_project_config(project_path.clone()).unwrap();
+        assert!(result.config.is_none());
+        let mut fields: Vec<&str> = result.errors.iter().map(|issue| issue.field.as_str()).collect();
+        fields.sort();
+        assert_eq!(fields, vec!["args", "port"]);
+
+        std::fs::write(project_dir.join(PROJECT_CONFIG_FILE), "{ not json").unwrap();
+        let result = load_project_config(project_path).unwrap();
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].field, "$");
+
+        // Cleanup
+        let _ = std::fs::remove_dir_all(project_dir);
+    }
+}
@@ -0,0 +1,556 @@
+use lazy_static::lazy_static;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::thread;
+
+/// Launch a helper command without blocking, reaping it in the background
+fn launch_detached(command: &mut Command) -> Result<()> {
+    let program = command.get_program().to_string_lossy().to_string();
+
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to launch '{}': {}", program, e),
+            )
+        })?;
+
+    thread::spawn(move || {
+        let _ = child.wait();
+    });
+
+    Ok(())
+}
+
+/// Reveal a file or folder in the system file manager
+///
+/// # Arguments
+/// * `path` - Path of the item to reveal
+///
+/// # Returns
+/// * `Result<()>` - Success or error if the path doesn't exist
+///
+/// # Platform Handling
+/// * macOS: `open -R` (Finder with the item selected)
+/// * Windows: `explorer /select,` (Explorer with the item selected)
+/// * Linux: `xdg-open` on the parent directory (selection isn't standardized)
+#[napi]
+pub fn reveal_in_file_manager(path: String) -> Result<()> {
+    let target = Path::new(&path);
+    if !target.exists() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Path does not exist: {}", path),
+        ));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        launch_detached(Command::new("open").arg("-R").arg(target))
+    }
+
+    #[cfg(windows)]
+    {
+        launch_detached(Command::new("explorer").arg(format!("/select,{}", path)))
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        let directory = if target.is_dir() {
+            target
+        } else {
+            target.parent().unwrap_or(target)
+        };
+        launch_detached(Command::new("xdg-open").arg(directory))
+    }
+}
+
+/// Open a file or an http(s) URL with the system default application
+///
+/// # Arguments
+/// * `target` - Existing file path, or a URL starting with `http://` or `https://`
+///
+/// # Returns
+/// * `Result<()>` - Success or error if the target is invalid
+///
+/// # Platform Handling
+/// * macOS: `open`
+/// * Windows: `rundll32 url.dll,FileProtocolHandler`, which hands the target to the shell
+///   without going through `cmd.exe`, so `&` in a URL's query string stays part of the URL
+/// * Linux: `xdg-open`
+#[napi]
+pub fn open_with_default_app(target: String) -> Result<()> {
+    let is_url = target.starts_with("http://") || target.starts_with("https://");
+
+    if !is_url {
+        if target.contains("://") {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!("Only http and https URLs can be opened: {}", target),
+            ));
+        }
+
+        if !Path::new(&target).exists() {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!("Path does not exist: {}", target),
+            ));
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        launch_detached(Command::new("open").arg(&target))
+    }
+
+    #[cfg(windows)]
+    {
+        launch_detached(
+            Command::new("rundll32")
+                .arg("url.dll,FileProtocolHandler")
+                .arg(&target),
+        )
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        launch_detached(Command::new("xdg-open").arg(&target))
+    }
+}
+
+lazy_static! {
+    /// One clipboard handle for the whole session: on X11 and Wayland, text copied through a
+    /// handle disappears when that handle is dropped, unless a clipboard manager took it over
+    static ref CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
+}
+
+/// Run `action` with the shared clipboard handle, connecting to the clipboard on first use
+fn with_clipboard<T>(
+    action: impl FnOnce(&mut arboard::Clipboard) -> std::result::Result<T, arboard::Error>,
+    failure: &str,
+) -> Result<T> {
+    let mut slot = CLIPBOARD
+        .lock()
+        .map_err(|_| Error::new(Status::GenericFailure, "Clipboard lock poisoned"))?;
+
+    let mut clipboard = match slot.take() {
+        Some(clipboard) => clipboard,
+        None => arboard::Clipboard::new().map_err(|e| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Clipboard is not available: {}", e),
+            )
+        })?,
+    };
+
+    let result = action(&mut clipboard);
+    *slot = Some(clipboard);
+
+    result.map_err(|e| Error::new(Status::GenericFailure, format!("{}: {}", failure, e)))
+}
+
+/// Copy text to the system clipboard
+///
+/// # Arguments
+/// * `text` - Text to place on the clipboard
+///
+/// # Returns
+/// * `Result<()>` - Success or error if no clipboard is available (e.g. headless CI)
+#[napi]
+pub fn set_clipboard_text(text: String) -> Result<()> {
+    with_clipboard(|clipboard| clipboard.set_text(text), "Failed to write clipboard")
+}
+
+/// Read text from the system clipboard
+///
+/// # Returns
+/// * `Result<String>` - Clipboard text, or error if no clipboard or no text is available
+#[napi]
+pub fn get_clipboard_text() -> Result<String> {
+    with_clipboard(|clipboard| clipboard.get_text(), "Failed to read clipboard")
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrowserInstall {
+    /// "Chrome", "Chromium", "Edge" or "Brave"
+    pub name: String,
+    pub executable_path: String,
+    /// Version such as "120.0.6099.109", when it could be determined
+    pub version: Option<String>,
+}
+
+/// Well-known browser executables for the current platform, in preference order
+fn browser_candidates() -> Vec<(&'static str, PathBuf)> {
+    let mut candidates = Vec::new();
+
+    #[cfg(target_os = "macos")]
+    {
+        let apps = [
+            ("Chrome", "Google Chrome.app/Contents/MacOS/Google Chrome"),
+            ("Chromium", "Chromium.app/Contents/MacOS/Chromium"),
+            ("Edge", "Microsoft Edge.app/Contents/MacOS/Microsoft Edge"),
+            ("Brave", "Brave Browser.app/Contents/MacOS/Brave Browser"),
+        ];
+        let mut roots = vec![PathBuf::from("/Applications")];
+        if let Some(home) = std::env::var_os("HOME") {
+            roots.push(PathBuf::from(home).join("Applications"));
+        }
+        for root in &roots {
+            for (name, relative) in apps {
+                candidates.push((name, root.join(relative)));
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        let apps = [
+            ("Chrome", r"Google\Chrome\Application\chrome.exe"),
+            ("Chromium", r"Chromium\Application\chrome.exe"),
+            ("Edge", r"Microsoft\Edge\Application\msedge.exe"),
+            ("Brave", r"BraveSoftware\Brave-Browser\Application\brave.exe"),
+        ];
+        let roots: Vec<PathBuf> = ["ProgramFiles", "ProgramFiles(x86)", "LOCALAPPDATA"]
+            .iter()
+            .filter_map(|var| std::env::var_os(var).map(PathBuf::from))
+            .collect();
+        for root in &roots {
+            for (name, relative) in apps {
+                candidates.push((name, root.join(relative)));
+            }
+        }
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        let binaries = [
+            ("Chrome", "google-chrome-stable"),
+            ("Chrome", "google-chrome"),
+            ("Chromium", "chromium"),
+            ("Chromium", "chromium-browser"),
+            ("Edge", "microsoft-edge-stable"),
+            ("Edge", "microsoft-edge"),
+            ("Brave", "brave-browser"),
+            ("Brave", "brave"),
+        ];
+        let mut dirs: Vec<PathBuf> = std::env::var_os("PATH")
+            .map(|path| std::env::split_paths(&path).collect())
+            .unwrap_or_default();
+        dirs.push(PathBuf::from("/snap/bin"));
+        for (name, binary) in binaries {
+            for dir in &dirs {
+                candidates.push((name, dir.join(binary)));
+            }
+        }
+    }
+
+    candidates
+}
+
+/// Extract a dotted version number from `--version` output (e.g. "Google Chrome 120.0.6099.109")
+fn parse_browser_version(output: &str) -> Option<String> {
+    output
+        .split_whitespace()
+        .find(|word| {
+            word.contains('.')
+                && word
+                    .split('.')
+                    .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(|word| word.to_string())
+}
+
+/// Determine an installed browser's version without launching a window
+fn browser_version(executable: &Path) -> Option<String> {
+    #[cfg(windows)]
+    {
+        // `--version` doesn't print on Windows; installers keep a folder named after the version
+        let application_dir = executable.parent()?;
+        std::fs::read_dir(application_dir)
+            .ok()?
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| parse_browser_version(&entry.file_name().to_string_lossy()))
+            .max_by_key(|version| {
+                version
+                    .split('.')
+                    .map(|part| part.parse::<u32>().unwrap_or(0))
+                    .collect::<Vec<_>>()
+            })
+    }
+
+    #[cfg(not(windows))]
+    {
+        let output = Command::new(executable)
+            .arg("--version")
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .ok()?;
+        parse_browser_version(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// List Chromium-based browsers installed on the system
+///
+/// # Returns
+/// * `Vec<BrowserInstall>` - Installed browsers, usable as `executable_path` when launching Chromium
+///
+/// # Platform Handling
+/// * macOS: `/Applications` and `~/Applications` app bundles
+/// * Windows: Program Files and per-user `%LOCALAPPDATA%` installs
+/// * Linux: known binary names on `PATH` and in `/snap/bin`
+#[napi]
+pub fn list_installed_browsers() -> Vec<BrowserInstall> {
+    let mut seen = HashSet::new();
+    let mut browsers = Vec::new();
+
+    for (name, path) in browser_candidates() {
+        if !path.is_file() {
+            continue;
+        }
+
+        // Launcher names are often symlinks to the same binary (google-chrome -> google-chrome-stable)
+        let resolved = path.canonicalize().unwrap_or_else(|_| path.clone());
+        if !seen.insert(resolved) {
+            continue;
+        }
+
+        browsers.push(BrowserInstall {
+            name: name.to_string(),
+            executable_path: path.to_string_lossy().to_string(),
+            version: browser_version(&path),
+        });
+    }
+
+    browsers
+}
+
+/// Find an executable by file name in the directories on `PATH`
+fn find_on_path(binary: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(binary))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Get the user's default shell
+///
+/// # Returns
+/// * `String` - Path or name of the shell commands should run in
+///
+/// # Platform Handling
+/// * Unix/Linux/macOS: `$SHELL`, falling back to `/bin/sh`
+/// * Windows: `%ComSpec%`, falling back to `cmd.exe`
+#[napi]
+pub fn get_default_shell() -> String {
+    #[cfg(windows)]
+    let (variable, fallback) = ("ComSpec", "cmd.exe");
+    #[cfg(not(windows))]
+    let (variable, fallback) = ("SHELL", "/bin/sh");
+
+    std::env::var(variable)
+        .ok()
+        .filter(|shell| !shell.trim().is_empty())
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShellInfo {
+    /// Shell name, e.g. "bash", "zsh", "pwsh", "cmd"
+    pub name: String,
+    pub path: String,
+}
+
+/// List the shells installed on the system
+///
+/// # Returns
+/// * `Vec<ShellInfo>` - Shells found on `PATH`, in a fixed preference order
+///
+/// # Platform Handling
+/// * Unix/Linux/macOS: bash, zsh, fish, sh, pwsh
+/// * Windows: cmd, powershell, pwsh, bash (Git Bash or WSL)
+#[napi]
+pub fn get_available_shells() -> Vec<ShellInfo> {
+    #[cfg(windows)]
+    let shells = [
+        ("cmd", "cmd.exe"),
+        ("powershell", "powershell.exe"),
+        ("pwsh", "pwsh.exe"),
+        ("bash", "bash.exe"),
+    ];
+    #[cfg(not(windows))]
+    let shells = [
+        ("bash", "bash"),
+        ("zsh", "zsh"),
+        ("fish", "fish"),
+        ("sh", "sh"),
+        ("pwsh", "pwsh"),
+    ];
+
+    shells
+        .iter()
+        .filter_map(|(name, binary)| {
+            find_on_path(binary).map(|path| ShellInfo {
+                name: name.to_string(),
+                path: path.to_string_lossy().to_string(),
+            })
+        })
+        .collect()
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuntimeEnvironment {
+    pub in_container: bool,
+    /// "docker", "podman", "kubernetes", "lxc" or "containerd", when it can be told apart
+    pub container_type: Option<String>,
+    /// Running under Windows Subsystem for Linux
+    pub in_wsl: bool,
+    pub is_ci: bool,
+}
+
+/// Environment variables set by common CI providers
+const CI_ENV_VARS: [&str; 9] = [
+    "CI",
+    "GITHUB_ACTIONS",
+    "GITLAB_CI",
+    "CIRCLECI",
+    "TRAVIS",
+    "BUILDKITE",
+    "JENKINS_URL",
+    "TF_BUILD",
+    "TEAMCITY_VERSION",
+];
+
+/// Identify the container runtime from the contents of `/proc/1/cgroup`
+fn container_type_from_cgroup(cgroup: &str) -> Option<&'static str> {
+    if cgroup.contains("kubepods") {
+        Some("kubernetes")
+    } else if cgroup.contains("docker") {
+        Some("docker")
+    } else if cgroup.contains("libpod") {
+        Some("podman")
+    } else if cgroup.contains("lxc") {
+        Some("lxc")
+    } else if cgroup.contains("containerd") {
+        Some("containerd")
+    } else {
+        None
+    }
+}
+
+/// Whether any CI provider variable is set to something other than "false" or "0"
+fn is_ci_env(lookup: &dyn Fn(&str) -> Option<String>) -> bool {
+    CI_ENV_VARS.iter().any(|name| {
+        lookup(name).is_some_and(|value| !matches!(value.trim().to_lowercase().as_str(), "" | "false" | "0"))
+    })
+}
+
+/// Detect whether the app runs in a container, under WSL or on a CI machine
+///
+/// # Returns
+/// * `RuntimeEnvironment` - Detected environment; Chromium typically needs `--no-sandbox`
+///   when `in_container` is true
+///
+/// # Platform Handling
+/// * Linux: `/.dockerenv`, `/run/.containerenv`, `/proc/1/cgroup` and the `container` and
+///   `KUBERNETES_SERVICE_HOST` variables for containers; `/proc/version` for WSL
+/// * macOS/Windows: Only CI detection applies
+#[napi]
+pub fn detect_runtime_environment() -> RuntimeEnvironment {
+    let lookup = |name: &str| std::env::var(name).ok();
+    let mut container_type: Option<String> = None;
+
+    if cfg!(target_os = "linux") {
+        let cgroup = std::fs::read_to_string("/proc/1/cgroup").unwrap_or_default();
+
+        container_type = if std::env::var_os("KUBERNETES_SERVICE_HOST").is_some() {
+            Some("kubernetes".to_string())
+        } else if Path::new("/.dockerenv").exists() {
+            Some("docker".to_string())
+        } else if Path::new("/run/.containerenv").exists() {
+            Some("podman".to_string())
+        } else {
+            // systemd-nspawn, podman and LXC set `container` for PID 1's environment
+            container_type_from_cgroup(&cgroup)
+                .map(String::from)
+                .or_else(|| lookup("container").filter(|value| !value.is_empty()))
+        };
+    }
+
+    let in_wsl = cfg!(target_os = "linux")
+        && (std::env::var_os("WSL_DISTRO_NAME").is_some()
+            || std::fs::read_to_string("/proc/version")
+                .map(|version| version.to_lowercase().contains("microsoft"))
+                .unwrap_or(false));
+
+    RuntimeEnvironment {
+        in_container: container_type.is_some(),
+        container_type,
+        in_wsl,
+        is_ci: is_ci_env(&lookup),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reveal_nonexistent_path() {
+        let result = reveal_in_file_manager("/nonexistent/path/12345".to_string());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_open_with_default_app_rejects_invalid_targets() {
+        assert!(open_with_default_app("/nonexistent/path/12345".to_string()).is_err());
+        assert!(open_with_default_app("file:///etc/passwd".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_parse_browser_version() {
+        assert_eq!(
+            parse_browser_version("Google Chrome 120.0.6099.109 \n"),
+            Some("120.0.6099.109".to_string())
+        );
+        assert_eq!(
+            parse_browser_version("Chromium 119.0.6045.159 built on Debian 12.2"),
+            Some("119.0.6045.159".to_string())
+        );
+        assert_eq!(parse_browser_version("no version here."), None);
+    }
+
+    #[test]
+    fn test_get_default_shell_and_available_shells() {
+        assert!(!get_default_shell().is_empty());
+
+        #[cfg(unix)]
+        assert!(get_available_shells().iter().any(|shell| shell.name == "sh"));
+    }
+
+    #[test]
+    fn test_runtime_environment_helpers() {
+        assert_eq!(
+            container_type_from_cgroup("0::/kubepods/besteffort/pod1234/abcdef"),
+            Some("kubernetes")
+        );
+        assert_eq!(container_type_from_cgroup("12:memory:/docker/3f4e2a"), Some("docker"));
+        assert_eq!(container_type_from_cgroup("0::/init.scope"), None);
+
+        assert!(is_ci_env(&|name| (name == "GITHUB_ACTIONS").then(|| "true".to_string())));
+        assert!(!is_ci_env(&|name| (name == "CI").then(|| "false".to_string())));
+    }
+}